@@ -20,7 +20,7 @@ lazy_static! {
 struct Model {
     index: Rc<Index>,
     results: Vec<Map<String, Value>>,
-    processing_time_ms: usize,
+    processing_time_ms: u64,
 
     // These two fields are used to avoid rollbacks by giving an ID to each request
     latest_sent_request_id: usize,
@@ -33,7 +33,7 @@ enum Msg {
     /// The event sent to display new results once they are received
     Update {
         results: Vec<Map<String, Value>>,
-        processing_time_ms: usize,
+        processing_time_ms: u64,
         request_id: usize,
     },
 }
@@ -139,7 +139,7 @@ impl Component for Model {
     }
 }
 
-fn header_content(processing_time_ms: usize, link: &Scope<Model>) -> Html {
+fn header_content(processing_time_ms: u64, link: &Scope<Model>) -> Html {
     html! {
         <div class="inner-col">
             <h3>{"Meili crates browser 2000"}</h3>
@@ -1,7 +1,15 @@
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    fmt,
+    iter::FromIterator,
+    ops::{Add, AddAssign, Not},
+};
+
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
-use crate::{client::Client, errors::Error, request::HttpClient};
+use crate::{client::Client, errors::Error, indexes::IndexUid, request::HttpClient};
 
 /// Types and queries for the Meilisearch Batches API.
 ///
@@ -10,7 +18,7 @@ use crate::{client::Client, errors::Error, request::HttpClient};
 #[serde(rename_all = "camelCase")]
 pub struct Batch {
     /// Unique identifier of the batch.
-    pub uid: u32,
+    pub uid: BatchUid,
     /// When the batch was enqueued.
     #[serde(default, with = "time::serde::rfc3339::option")]
     pub enqueued_at: Option<OffsetDateTime>,
@@ -21,16 +29,304 @@ pub struct Batch {
     #[serde(default, with = "time::serde::rfc3339::option")]
     pub finished_at: Option<OffsetDateTime>,
     /// Index uid related to this batch (if applicable).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    ///
+    /// Only present in some batch responses.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub index_uid: Option<String>,
     /// The task uids that are part of this batch.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_uids: Option<Vec<u32>>,
+    #[serde(default)]
+    pub task_uids: Vec<u32>,
     /// The strategy that caused the autobatcher to stop batching tasks.
     ///
     /// Introduced in Meilisearch v1.15.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub batch_strategy: Option<BatchStrategy>,
+    /// Statistics about the tasks of this batch.
+    #[serde(default)]
+    pub stats: Option<BatchStats>,
+    /// Progress of the batch, only present while it is processing.
+    #[serde(default)]
+    pub progress: Option<BatchProgress>,
+}
+
+impl Batch {
+    /// Returns `true` if the task with the given uid is part of this batch.
+    #[must_use]
+    pub fn contains_task(&self, task_uid: u32) -> bool {
+        self.task_uids.contains(&task_uid)
+    }
+
+    /// Returns the number of tasks in this batch.
+    #[must_use]
+    pub fn task_count(&self) -> usize {
+        self.task_uids.len()
+    }
+}
+
+/// Progress of a processing [`Batch`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchProgress {
+    /// The nested steps the batch is going through, from the outermost to the innermost.
+    pub steps: Vec<BatchProgressStep>,
+    /// Overall completion of the batch, in percent.
+    pub percentage: f64,
+}
+
+impl BatchProgress {
+    /// Returns the innermost step, which is the one currently being processed.
+    #[must_use]
+    pub fn current_step(&self) -> Option<&BatchProgressStep> {
+        self.steps.last()
+    }
+
+    /// Returns the name of the [current step](BatchProgress::current_step).
+    #[must_use]
+    pub fn current_step_name(&self) -> Option<&str> {
+        self.current_step().map(|step| step.current_step.as_str())
+    }
+}
+
+/// A step of a [`BatchProgress`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchProgressStep {
+    /// Name of the step.
+    pub current_step: String,
+    /// Number of finished units of work in this step.
+    pub finished: i32,
+    /// Total number of units of work in this step.
+    pub total: i32,
+}
+
+impl BatchProgressStep {
+    /// Returns the completion of this step, between `0.0` and `1.0`.
+    ///
+    /// Steps without any unit of work are reported as `0.0`.
+    #[must_use]
+    pub fn fraction(&self) -> f64 {
+        if self.total <= 0 {
+            return 0.0;
+        }
+
+        (f64::from(self.finished) / f64::from(self.total)).clamp(0.0, 1.0)
+    }
+}
+
+/// Statistics about the tasks of a [`Batch`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchStats {
+    /// Number of tasks in the batch.
+    pub total_nb_tasks: u32,
+    /// Number of tasks in the batch, per status.
+    #[serde(default)]
+    pub status: Statuses,
+    /// Number of tasks in the batch, per type.
+    #[serde(default)]
+    pub types: Types,
+    /// Number of tasks in the batch, per index uid.
+    #[serde(default)]
+    pub index_uids: HashMap<IndexUid, u32>,
+    /// Time spent in each step of the batch processing.
+    ///
+    /// Not sent by older Meilisearch versions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress_trace: Option<HashMap<String, String>>,
+    /// Congestion of the channel used to write to the database.
+    ///
+    /// Not sent by older or lightweight Meilisearch deployments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub write_channel_congestion: Option<WriteChannelCongestion>,
+    /// Size of the internal databases, and their variation during the batch.
+    ///
+    /// Not sent by older or lightweight Meilisearch deployments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub internal_database_sizes: Option<HashMap<String, String>>,
+}
+
+/// Sums two optional counts, treating `None` as zero.
+fn add_counts(a: Option<u32>, b: Option<u32>) -> Option<u32> {
+    match (a, b) {
+        (None, None) => None,
+        (a, b) => Some(a.unwrap_or(0).saturating_add(b.unwrap_or(0))),
+    }
+}
+
+impl Add for Statuses {
+    type Output = Statuses;
+
+    fn add(self, other: Statuses) -> Statuses {
+        Statuses {
+            enqueued: add_counts(self.enqueued, other.enqueued),
+            processing: add_counts(self.processing, other.processing),
+            succeeded: add_counts(self.succeeded, other.succeeded),
+            failed: add_counts(self.failed, other.failed),
+            canceled: add_counts(self.canceled, other.canceled),
+        }
+    }
+}
+
+impl AddAssign for Statuses {
+    fn add_assign(&mut self, other: Statuses) {
+        *self = *self + other;
+    }
+}
+
+impl Add for Types {
+    type Output = Types;
+
+    fn add(self, other: Types) -> Types {
+        Types {
+            document_addition_or_update: add_counts(
+                self.document_addition_or_update,
+                other.document_addition_or_update,
+            ),
+            document_edition: add_counts(self.document_edition, other.document_edition),
+            document_deletion: add_counts(self.document_deletion, other.document_deletion),
+            settings_update: add_counts(self.settings_update, other.settings_update),
+            index_creation: add_counts(self.index_creation, other.index_creation),
+            index_deletion: add_counts(self.index_deletion, other.index_deletion),
+            index_update: add_counts(self.index_update, other.index_update),
+            index_swap: add_counts(self.index_swap, other.index_swap),
+            index_compaction: add_counts(self.index_compaction, other.index_compaction),
+            task_cancelation: add_counts(self.task_cancelation, other.task_cancelation),
+            task_deletion: add_counts(self.task_deletion, other.task_deletion),
+            dump_creation: add_counts(self.dump_creation, other.dump_creation),
+            snapshot_creation: add_counts(self.snapshot_creation, other.snapshot_creation),
+            upgrade_database: add_counts(self.upgrade_database, other.upgrade_database),
+            network_topology_change: add_counts(
+                self.network_topology_change,
+                other.network_topology_change,
+            ),
+        }
+    }
+}
+
+impl AddAssign for Types {
+    fn add_assign(&mut self, other: Types) {
+        *self = *self + other;
+    }
+}
+
+/// Congestion of the write channel during a [`Batch`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WriteChannelCongestion {
+    /// Number of attempts to write to the channel.
+    pub attempts: u64,
+    /// Number of attempts that were blocked because the channel was full.
+    pub blocking_attempts: u64,
+    /// Ratio of blocked attempts.
+    pub blocking_ratio: f64,
+}
+
+/// Number of tasks per status in [`BatchStats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Statuses {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enqueued: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub processing: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub succeeded: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failed: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canceled: Option<u32>,
+}
+
+/// Number of tasks per type in [`BatchStats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Types {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_addition_or_update: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_edition: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_deletion: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings_update: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index_creation: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index_deletion: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index_update: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index_swap: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index_compaction: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_cancelation: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_deletion: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dump_creation: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot_creation: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upgrade_database: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_topology_change: Option<u32>,
+}
+
+/// Writes the non-zero counts as `name: count`, separated by commas.
+fn write_counts(f: &mut fmt::Formatter<'_>, counts: &[(&str, Option<u32>)]) -> fmt::Result {
+    let mut counts = counts
+        .iter()
+        .filter_map(|(name, count)| count.filter(|count| *count != 0).map(|count| (name, count)));
+
+    if let Some((name, count)) = counts.next() {
+        write!(f, "{name}: {count}")?;
+    }
+    for (name, count) in counts {
+        write!(f, ", {name}: {count}")?;
+    }
+    Ok(())
+}
+
+/// Lists the non-zero counts, like `succeeded: 5, failed: 1`.
+impl fmt::Display for Statuses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_counts(
+            f,
+            &[
+                ("enqueued", self.enqueued),
+                ("processing", self.processing),
+                ("succeeded", self.succeeded),
+                ("failed", self.failed),
+                ("canceled", self.canceled),
+            ],
+        )
+    }
+}
+
+/// Lists the non-zero counts with the names used by Meilisearch, like `documentAdditionOrUpdate: 3, settingsUpdate: 1`.
+impl fmt::Display for Types {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_counts(
+            f,
+            &[
+                ("documentAdditionOrUpdate", self.document_addition_or_update),
+                ("documentEdition", self.document_edition),
+                ("documentDeletion", self.document_deletion),
+                ("settingsUpdate", self.settings_update),
+                ("indexCreation", self.index_creation),
+                ("indexDeletion", self.index_deletion),
+                ("indexUpdate", self.index_update),
+                ("indexSwap", self.index_swap),
+                ("indexCompaction", self.index_compaction),
+                ("taskCancelation", self.task_cancelation),
+                ("taskDeletion", self.task_deletion),
+                ("dumpCreation", self.dump_creation),
+                ("snapshotCreation", self.snapshot_creation),
+                ("upgradeDatabase", self.upgrade_database),
+                ("networkTopologyChange", self.network_topology_change),
+            ],
+        )
+    }
 }
 
 /// Reason why the autobatcher stopped batching tasks.
@@ -47,20 +343,181 @@ pub enum BatchStrategy {
     Unknown,
 }
 
+/// Swaps the size and time limits, [`BatchStrategy::Unknown`] stays unknown.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::batches::BatchStrategy;
+/// assert_eq!(!BatchStrategy::SizeLimitReached, BatchStrategy::TimeLimitReached);
+/// ```
+impl Not for BatchStrategy {
+    type Output = BatchStrategy;
+
+    fn not(self) -> BatchStrategy {
+        match self {
+            BatchStrategy::SizeLimitReached => BatchStrategy::TimeLimitReached,
+            BatchStrategy::TimeLimitReached => BatchStrategy::SizeLimitReached,
+            BatchStrategy::Unknown => BatchStrategy::Unknown,
+        }
+    }
+}
+
+/// The uid of a [`Batch`].
+///
+/// Batch uids share the `u32` representation of task uids; this newtype keeps the two from being mixed up.
+/// Meilisearch never returns a negative or a larger uid, so it wraps a `u32` rather than an `i64`:
+/// it converts losslessly into an `i64`, and [`TryFrom<i64>`](BatchUid#impl-TryFrom<i64>-for-BatchUid) rejects the values out of range.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::batches::BatchUid;
+/// let uid = BatchUid::from(42);
+///
+/// assert_eq!(i64::from(uid), 42);
+/// assert_eq!(uid.to_string(), "42");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct BatchUid(pub u32);
+
+impl std::fmt::Display for BatchUid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<u32> for BatchUid {
+    fn from(uid: u32) -> Self {
+        BatchUid(uid)
+    }
+}
+
+impl From<&u32> for BatchUid {
+    fn from(uid: &u32) -> Self {
+        BatchUid(*uid)
+    }
+}
+
+impl From<&BatchUid> for BatchUid {
+    fn from(uid: &BatchUid) -> Self {
+        *uid
+    }
+}
+
+impl From<&Batch> for BatchUid {
+    fn from(batch: &Batch) -> Self {
+        batch.uid
+    }
+}
+
+impl TryFrom<i64> for BatchUid {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(uid: i64) -> Result<Self, Self::Error> {
+        u32::try_from(uid).map(BatchUid)
+    }
+}
+
+impl PartialEq<u32> for BatchUid {
+    fn eq(&self, other: &u32) -> bool {
+        self.0 == *other
+    }
+}
+
+impl From<BatchUid> for u32 {
+    fn from(uid: BatchUid) -> Self {
+        uid.0
+    }
+}
+
+impl From<BatchUid> for i64 {
+    fn from(uid: BatchUid) -> Self {
+        uid.0.into()
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchesResults {
     pub results: Vec<Batch>,
     pub total: u32,
     pub limit: u32,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub from: Option<u32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub next: Option<u32>,
 }
 
+impl IntoIterator for BatchesResults {
+    type Item = Batch;
+    type IntoIter = std::vec::IntoIter<Batch>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.into_iter()
+    }
+}
+
+/// Appends the batches to [`results`](BatchesResults::results) and counts them in [`total`](BatchesResults::total).
+///
+/// **Note: the [`total`](BatchesResults::total) of a page already counts the batches of the next pages of the same query,
+/// extending a page with them counts them twice. [`limit`](BatchesResults::limit), [`from`](BatchesResults::from)
+/// and [`next`](BatchesResults::next) are left untouched and still describe the first page.**
+impl Extend<Batch> for BatchesResults {
+    fn extend<I: IntoIterator<Item = Batch>>(&mut self, iter: I) {
+        let len = self.results.len();
+        self.results.extend(iter);
+        let added = u32::try_from(self.results.len() - len).unwrap_or(u32::MAX);
+        self.total = self.total.saturating_add(added);
+    }
+}
+
+/// Builds a single page holding all the batches.
+///
+/// [`total`](BatchesResults::total) and [`limit`](BatchesResults::limit) are both the number of batches, as if they had been
+/// fetched at once with a limit fitting them all. The value doesn't come from a query, so it has no
+/// [`from`](BatchesResults::from) or [`next`](BatchesResults::next) cursor.
+impl FromIterator<Batch> for BatchesResults {
+    fn from_iter<I: IntoIterator<Item = Batch>>(iter: I) -> Self {
+        let results: Vec<Batch> = iter.into_iter().collect();
+        let total = u32::try_from(results.len()).unwrap_or(u32::MAX);
+
+        BatchesResults {
+            results,
+            total,
+            limit: total,
+            from: None,
+            next: None,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a BatchesResults {
+    type Item = &'a Batch;
+    type IntoIter = std::slice::Iter<'a, Batch>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.iter()
+    }
+}
+
+impl BatchesResults {
+    /// Returns the batches of this page that contain the task with the given uid.
+    #[must_use]
+    pub fn batches_containing_task(&self, task_uid: u32) -> Vec<&Batch> {
+        self.results
+            .iter()
+            .filter(|batch| batch.contains_task(task_uid))
+            .collect()
+    }
+}
+
+/// Maximum `limit` accepted by the `/batches` route.
+const MAX_BATCHES_LIMIT: u32 = 20_000;
+
 /// Query builder for listing batches.
-#[derive(Debug, Serialize, Clone)]
+#[derive(Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchesQuery<'a, Http: HttpClient> {
     #[serde(skip_serializing)]
@@ -71,6 +528,66 @@ pub struct BatchesQuery<'a, Http: HttpClient> {
     /// The first batch uid that should be returned.
     #[serde(skip_serializing_if = "Option::is_none")]
     from: Option<u32>,
+    /// Uids of the batches to retrieve.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "uids")]
+    batch_uids: Option<Vec<BatchUid>>,
+    /// Index uids array to only retrieve the batches of the indexes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index_uids: Option<Vec<&'a str>>,
+    /// Date to retrieve all batches that were enqueued before it.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "time::serde::rfc3339::option::serialize"
+    )]
+    before_enqueued_at: Option<OffsetDateTime>,
+    /// Date to retrieve all batches that were enqueued after it.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "time::serde::rfc3339::option::serialize"
+    )]
+    after_enqueued_at: Option<OffsetDateTime>,
+    /// Date to retrieve all batches that were started before it.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "time::serde::rfc3339::option::serialize"
+    )]
+    before_started_at: Option<OffsetDateTime>,
+    /// Date to retrieve all batches that were started after it.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "time::serde::rfc3339::option::serialize"
+    )]
+    after_started_at: Option<OffsetDateTime>,
+    /// Date to retrieve all batches that were finished before it.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "time::serde::rfc3339::option::serialize"
+    )]
+    before_finished_at: Option<OffsetDateTime>,
+    /// Date to retrieve all batches that were finished after it.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "time::serde::rfc3339::option::serialize"
+    )]
+    after_finished_at: Option<OffsetDateTime>,
+}
+
+/// Only shows the filters, the client is left out.
+impl<Http: HttpClient> std::fmt::Debug for BatchesQuery<'_, Http> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BatchesQuery")
+            .field("limit", &self.limit)
+            .field("from", &self.from)
+            .field("batch_uids", &self.batch_uids)
+            .field("index_uids", &self.index_uids)
+            .field("before_enqueued_at", &self.before_enqueued_at)
+            .field("after_enqueued_at", &self.after_enqueued_at)
+            .field("before_started_at", &self.before_started_at)
+            .field("after_started_at", &self.after_started_at)
+            .field("before_finished_at", &self.before_finished_at)
+            .field("after_finished_at", &self.after_finished_at)
+            .finish()
+    }
 }
 
 impl<'a, Http: HttpClient> BatchesQuery<'a, Http> {
@@ -80,22 +597,214 @@ impl<'a, Http: HttpClient> BatchesQuery<'a, Http> {
             client,
             limit: None,
             from: None,
+            batch_uids: None,
+            index_uids: None,
+            before_enqueued_at: None,
+            after_enqueued_at: None,
+            before_started_at: None,
+            after_started_at: None,
+            before_finished_at: None,
+            after_finished_at: None,
         }
     }
 
+    /// Maximum number of batches to return.
+    ///
+    /// Meilisearch doesn't accept more than 20 000 batches per page, larger values are capped.
+    /// A limit of `0` is rejected when the query is [validated](BatchesQuery::validate).
     #[must_use]
     pub fn with_limit(&mut self, limit: u32) -> &mut Self {
-        self.limit = Some(limit);
+        self.limit = Some(limit.min(MAX_BATCHES_LIMIT));
         self
     }
 
+    /// Uid of the first batch to return.
+    ///
+    /// Batches are listed from the most recent to the oldest, so only the batches with a uid lower than or equal to `from` are returned.
+    ///
+    /// **`from` is a cursor: it should come from the [`next`](BatchesResults::next) field of a previous page rather than being computed.**
     #[must_use]
     pub fn with_from(&mut self, from: u32) -> &mut Self {
         self.from = Some(from);
         self
     }
 
+    /// Ask for the page following `page`, with the same limit.
+    ///
+    /// `from` is set to the [`next`](BatchesResults::next) cursor of `page`, since it's a batch uid and not an offset
+    /// it can't be computed from a page number. Returns `None` if `page` was the last page.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, batches::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let mut query = BatchesQuery::new(&client);
+    /// let mut page = query.with_limit(10).execute().await.unwrap();
+    ///
+    /// while let Some(next) = query.with_page_after(&page) {
+    ///     page = next.execute().await.unwrap();
+    /// }
+    /// # });
+    /// ```
+    pub fn with_page_after(&mut self, page: &BatchesResults) -> Option<&mut Self> {
+        let from = page.next?;
+        self.from = Some(from);
+        self.limit = Some(page.limit);
+        Some(self)
+    }
+
+    #[must_use]
+    pub fn with_batch_uids(
+        &mut self,
+        batch_uids: impl IntoIterator<Item = impl Into<BatchUid>>,
+    ) -> &mut Self {
+        self.batch_uids = Some(batch_uids.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Only return the batches that processed tasks of these indexes.
+    #[must_use]
+    pub fn with_index_uids(&mut self, index_uids: impl IntoIterator<Item = &'a str>) -> &mut Self {
+        self.index_uids = Some(index_uids.into_iter().collect());
+        self
+    }
+
+    #[must_use]
+    pub fn with_before_enqueued_at(&mut self, before_enqueued_at: OffsetDateTime) -> &mut Self {
+        self.before_enqueued_at = Some(before_enqueued_at);
+        self
+    }
+
+    #[must_use]
+    pub fn with_after_enqueued_at(&mut self, after_enqueued_at: OffsetDateTime) -> &mut Self {
+        self.after_enqueued_at = Some(after_enqueued_at);
+        self
+    }
+
+    #[must_use]
+    pub fn with_before_started_at(&mut self, before_started_at: OffsetDateTime) -> &mut Self {
+        self.before_started_at = Some(before_started_at);
+        self
+    }
+
+    #[must_use]
+    pub fn with_after_started_at(&mut self, after_started_at: OffsetDateTime) -> &mut Self {
+        self.after_started_at = Some(after_started_at);
+        self
+    }
+
+    #[must_use]
+    pub fn with_before_finished_at(&mut self, before_finished_at: OffsetDateTime) -> &mut Self {
+        self.before_finished_at = Some(before_finished_at);
+        self
+    }
+
+    #[must_use]
+    pub fn with_after_finished_at(&mut self, after_finished_at: OffsetDateTime) -> &mut Self {
+        self.after_finished_at = Some(after_finished_at);
+        self
+    }
+
+    /// Owned variant of [`BatchesQuery::with_limit`], to chain from [`BatchesQuery::new`].
+    #[must_use]
+    pub fn with_limit_owned(mut self, limit: u32) -> Self {
+        self.limit = Some(limit.min(MAX_BATCHES_LIMIT));
+        self
+    }
+
+    /// Owned variant of [`BatchesQuery::with_from`].
+    #[must_use]
+    pub fn with_from_owned(mut self, from: u32) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Owned variant of [`BatchesQuery::with_page_after`].
+    pub fn with_page_after_owned(mut self, page: &BatchesResults) -> Option<Self> {
+        self.with_page_after(page)?;
+        Some(self)
+    }
+
+    /// Owned variant of [`BatchesQuery::with_batch_uids`].
+    #[must_use]
+    pub fn with_batch_uids_owned(
+        mut self,
+        batch_uids: impl IntoIterator<Item = impl Into<BatchUid>>,
+    ) -> Self {
+        self.batch_uids = Some(batch_uids.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Owned variant of [`BatchesQuery::with_index_uids`].
+    #[must_use]
+    pub fn with_index_uids_owned(mut self, index_uids: impl IntoIterator<Item = &'a str>) -> Self {
+        self.index_uids = Some(index_uids.into_iter().collect());
+        self
+    }
+
+    /// Owned variant of [`BatchesQuery::with_before_enqueued_at`].
+    #[must_use]
+    pub fn with_before_enqueued_at_owned(mut self, before_enqueued_at: OffsetDateTime) -> Self {
+        self.before_enqueued_at = Some(before_enqueued_at);
+        self
+    }
+
+    /// Owned variant of [`BatchesQuery::with_after_enqueued_at`].
+    #[must_use]
+    pub fn with_after_enqueued_at_owned(mut self, after_enqueued_at: OffsetDateTime) -> Self {
+        self.after_enqueued_at = Some(after_enqueued_at);
+        self
+    }
+
+    /// Owned variant of [`BatchesQuery::with_before_started_at`].
+    #[must_use]
+    pub fn with_before_started_at_owned(mut self, before_started_at: OffsetDateTime) -> Self {
+        self.before_started_at = Some(before_started_at);
+        self
+    }
+
+    /// Owned variant of [`BatchesQuery::with_after_started_at`].
+    #[must_use]
+    pub fn with_after_started_at_owned(mut self, after_started_at: OffsetDateTime) -> Self {
+        self.after_started_at = Some(after_started_at);
+        self
+    }
+
+    /// Owned variant of [`BatchesQuery::with_before_finished_at`].
+    #[must_use]
+    pub fn with_before_finished_at_owned(mut self, before_finished_at: OffsetDateTime) -> Self {
+        self.before_finished_at = Some(before_finished_at);
+        self
+    }
+
+    /// Owned variant of [`BatchesQuery::with_after_finished_at`].
+    #[must_use]
+    pub fn with_after_finished_at_owned(mut self, after_finished_at: OffsetDateTime) -> Self {
+        self.after_finished_at = Some(after_finished_at);
+        self
+    }
+
+    /// Check the query before sending it.
+    ///
+    /// Returns [`Error::InvalidArgument`] if the limit was set to `0`.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.limit == Some(0) {
+            return Err(Error::InvalidArgument(
+                "the limit of a batches query must be at least 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
     /// Execute the query and list batches.
+    ///
+    /// The query is [validated](BatchesQuery::validate) first.
     pub async fn execute(&self) -> Result<BatchesResults, Error> {
         self.client.get_batches_with(self).await
     }
@@ -103,8 +812,14 @@ impl<'a, Http: HttpClient> BatchesQuery<'a, Http> {
 
 #[cfg(test)]
 mod tests {
-    use crate::batches::BatchStrategy;
+    use crate::batches::{
+        Batch, BatchProgress, BatchProgressStep, BatchStats, BatchStrategy, BatchUid,
+        BatchesResults, Statuses, Types,
+    };
     use crate::client::Client;
+    use crate::indexes::IndexUid;
+    use std::convert::TryFrom;
+    use time::{format_description::well_known::Rfc3339, OffsetDateTime};
 
     #[tokio::test]
     async fn test_get_batches_parses_batch_strategy() {
@@ -143,6 +858,20 @@ mod tests {
         assert_eq!(batches.results.len(), 1);
         let b = &batches.results[0];
         assert_eq!(b.uid, 42);
+        assert_eq!(
+            b.enqueued_at,
+            Some(OffsetDateTime::parse("2024-10-11T11:49:53.000Z", &Rfc3339).unwrap())
+        );
+        assert_eq!(
+            b.started_at,
+            Some(OffsetDateTime::parse("2024-10-11T11:49:54.000Z", &Rfc3339).unwrap())
+        );
+        assert_eq!(
+            b.finished_at,
+            Some(OffsetDateTime::parse("2024-10-11T11:49:55.000Z", &Rfc3339).unwrap())
+        );
+        assert_eq!(b.index_uid, Some("movies".to_string()));
+        assert_eq!(b.task_uids, vec![1, 2, 3]);
         assert_eq!(b.batch_strategy, Some(BatchStrategy::TimeLimitReached));
     }
 
@@ -169,6 +898,8 @@ mod tests {
         let client = Client::new(base, None::<String>).unwrap();
         let batch = client.get_batch(99).await.expect("get batch failed");
         assert_eq!(batch.uid, 99);
+        assert_eq!(batch.enqueued_at, None);
+        assert_eq!(batch.index_uid, None);
         assert_eq!(batch.batch_strategy, Some(BatchStrategy::SizeLimitReached));
     }
 
@@ -196,4 +927,566 @@ mod tests {
         let res = client.get_batches_with(&q).await.expect("request failed");
         assert_eq!(res.limit, 2);
     }
+
+    #[test]
+    fn test_batches_query_with_page_after() {
+        let client = Client::new("http://localhost:7700", Some("masterKey")).unwrap();
+        let page: BatchesResults = serde_json::from_str(
+            r#"{ "results": [{ "uid": 42 }, { "uid": 41 }], "total": 43, "limit": 2, "from": 42, "next": 40 }"#,
+        )
+        .unwrap();
+        let last_page: BatchesResults = serde_json::from_str(
+            r#"{ "results": [{ "uid": 0 }], "total": 43, "limit": 2, "from": 0 }"#,
+        )
+        .unwrap();
+
+        let mut query = crate::batches::BatchesQuery::new(&client);
+        let query = query.with_page_after(&page).unwrap();
+        assert_eq!(query.from, Some(40));
+        assert_eq!(query.limit, Some(2));
+        assert!(query.with_page_after(&last_page).is_none());
+        // the query is left untouched after the last page
+        assert_eq!(query.from, Some(40));
+
+        let query = crate::batches::BatchesQuery::new(&client)
+            .with_page_after_owned(&page)
+            .unwrap();
+        assert_eq!(query.from, Some(40));
+        assert!(query.with_page_after_owned(&last_page).is_none());
+    }
+
+    #[test]
+    fn test_batch_task_helpers() {
+        let batch: Batch = serde_json::from_str(r#"{"uid": 1, "taskUids": [4, 5, 6]}"#).unwrap();
+        assert!(batch.contains_task(5));
+        assert!(!batch.contains_task(7));
+        assert_eq!(batch.task_count(), 3);
+
+        let empty: Batch = serde_json::from_str(r#"{"uid": 2}"#).unwrap();
+        assert!(!empty.contains_task(0));
+        assert_eq!(empty.task_count(), 0);
+    }
+
+    #[test]
+    fn test_batches_containing_task() {
+        let batches: BatchesResults = serde_json::from_str(
+            r#"{
+                "results": [
+                    { "uid": 3, "taskUids": [7, 8] },
+                    { "uid": 2, "taskUids": [4, 5, 6] },
+                    { "uid": 1, "taskUids": [1, 7] }
+                ],
+                "total": 3,
+                "limit": 20
+            }"#,
+        )
+        .unwrap();
+
+        let containing = batches.batches_containing_task(7);
+        assert_eq!(
+            containing.iter().map(|batch| batch.uid).collect::<Vec<_>>(),
+            vec![3, 1]
+        );
+        assert!(batches.batches_containing_task(42).is_empty());
+    }
+
+    #[test]
+    fn test_iterate_over_batches_results() {
+        let batches: BatchesResults = serde_json::from_str(
+            r#"{
+                "results": [{ "uid": 3 }, { "uid": 2 }, { "uid": 1 }],
+                "total": 3,
+                "limit": 20
+            }"#,
+        )
+        .unwrap();
+
+        let mut uids = Vec::new();
+        for batch in &batches {
+            uids.push(batch.uid);
+        }
+        for batch in batches {
+            uids.push(batch.uid);
+        }
+        assert_eq!(uids, vec![3, 2, 1, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_extend_batches_results() {
+        let page: BatchesResults = serde_json::from_str(
+            r#"{ "results": [{ "uid": 5 }, { "uid": 4 }, { "uid": 3 }, { "uid": 2 }], "total": 4, "limit": 20 }"#,
+        )
+        .unwrap();
+        let (first, others): (Vec<Batch>, Vec<Batch>) =
+            page.into_iter().partition(|batch| batch.uid > BatchUid(3));
+        let mut batches: BatchesResults = first.into_iter().collect();
+
+        batches.extend(others);
+
+        assert_eq!(
+            batches
+                .results
+                .iter()
+                .map(|batch| batch.uid)
+                .collect::<Vec<_>>(),
+            vec![5, 4, 3, 2]
+        );
+        assert_eq!(batches.total, 4);
+
+        batches.total = u32::MAX - 1;
+        batches.extend(vec![batches.results[0].clone(); 2]);
+        assert_eq!(batches.total, u32::MAX);
+    }
+
+    #[test]
+    fn test_collect_batches_results() {
+        let page: BatchesResults = serde_json::from_str(
+            r#"{ "results": [{ "uid": 3 }, { "uid": 2 }, { "uid": 1 }], "total": 3, "limit": 20 }"#,
+        )
+        .unwrap();
+
+        let batches: BatchesResults = page.into_iter().filter(|batch| batch.uid != 2).collect();
+
+        assert_eq!(
+            batches
+                .results
+                .iter()
+                .map(|batch| batch.uid)
+                .collect::<Vec<_>>(),
+            vec![3, 1]
+        );
+        assert_eq!(batches.total, 2);
+        assert_eq!(batches.limit, 2);
+        assert_eq!(batches.from, None);
+        assert_eq!(batches.next, None);
+
+        let empty: BatchesResults = std::iter::empty().collect();
+        assert!(empty.results.is_empty());
+        assert_eq!(empty.total, 0);
+    }
+
+    #[test]
+    fn test_batches_query_debug_hides_client() {
+        let client = Client::new("http://localhost:7700", Some("masterKey")).unwrap();
+        let query = crate::batches::BatchesQuery::new(&client)
+            .with_limit_owned(10)
+            .with_batch_uids_owned([BatchUid(1)]);
+
+        assert_eq!(
+            format!("{:?}", query),
+            "BatchesQuery { limit: Some(10), from: None, batch_uids: Some([BatchUid(1)]), \
+             index_uids: None, before_enqueued_at: None, after_enqueued_at: None, before_started_at: None, \
+             after_started_at: None, before_finished_at: None, after_finished_at: None }"
+        );
+    }
+
+    #[test]
+    fn test_not_batch_strategy() {
+        assert_eq!(
+            !BatchStrategy::SizeLimitReached,
+            BatchStrategy::TimeLimitReached
+        );
+        assert_eq!(
+            !BatchStrategy::TimeLimitReached,
+            BatchStrategy::SizeLimitReached
+        );
+        assert_eq!(!BatchStrategy::Unknown, BatchStrategy::Unknown);
+    }
+
+    #[tokio::test]
+    async fn test_batches_zero_limit_is_rejected() {
+        use crate::errors::Error;
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        let mock = s.mock("GET", "/batches").expect(0).create_async().await;
+
+        let mut query = crate::batches::BatchesQuery::new(&client);
+        let _ = query.with_limit(0);
+        assert!(matches!(query.validate(), Err(Error::InvalidArgument(_))));
+        assert!(matches!(
+            query.execute().await,
+            Err(Error::InvalidArgument(_))
+        ));
+        mock.assert_async().await;
+
+        let _ = query.with_limit(1);
+        assert!(query.validate().is_ok());
+    }
+
+    #[test]
+    fn test_batches_limit_is_capped() {
+        let client = Client::new("http://localhost:7700", None::<String>).unwrap();
+
+        let mut query = crate::batches::BatchesQuery::new(&client);
+        let _ = query.with_limit(20_001);
+        assert_eq!(serde_json::to_value(&query).unwrap()["limit"], 20_000);
+
+        let query = crate::batches::BatchesQuery::new(&client).with_limit_owned(20_000);
+        assert_eq!(serde_json::to_value(&query).unwrap()["limit"], 20_000);
+
+        let query = crate::batches::BatchesQuery::new(&client).with_limit_owned(20);
+        assert_eq!(serde_json::to_value(&query).unwrap()["limit"], 20);
+    }
+
+    #[tokio::test]
+    async fn test_owned_builders_for_batches() {
+        use mockito::Matcher;
+        let mut s = mockito::Server::new_async().await;
+        let base = s.url();
+
+        let _m = s
+            .mock("GET", "/batches")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("limit".into(), "5".into()),
+                Matcher::UrlEncoded("from".into(), "0".into()),
+                Matcher::UrlEncoded("uids".into(), "3".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"results":[],"limit":5,"total":0}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(base, None::<String>).unwrap();
+        let res = crate::batches::BatchesQuery::new(&client)
+            .with_limit_owned(5)
+            .with_from_owned(0)
+            .with_batch_uids_owned([3])
+            .execute()
+            .await
+            .expect("request failed");
+        assert_eq!(res.limit, 5);
+    }
+
+    #[tokio::test]
+    async fn test_query_serialization_for_batch_uids() {
+        use mockito::Matcher;
+        let mut s = mockito::Server::new_async().await;
+        let base = s.url();
+
+        let _m = s
+            .mock("GET", "/batches")
+            .match_query(Matcher::UrlEncoded("uids".into(), "1,2".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"results":[],"limit":20,"total":0}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(base, None::<String>).unwrap();
+        let mut q = crate::batches::BatchesQuery::new(&client);
+        let _ = q.with_batch_uids([1, 2]);
+        let res = client.get_batches_with(&q).await.expect("request failed");
+        assert_eq!(res.total, 0);
+    }
+
+    #[tokio::test]
+    async fn test_query_serialization_for_batch_dates() {
+        use mockito::Matcher;
+        let mut s = mockito::Server::new_async().await;
+        let base = s.url();
+
+        let _m = s
+            .mock("GET", "/batches")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("beforeEnqueuedAt".into(), "2024-01-01T00:00:00Z".into()),
+                Matcher::UrlEncoded(
+                    "afterFinishedAt".into(),
+                    "2023-02-03T13:02:38.369634Z".into(),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"results":[],"limit":20,"total":0}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(base, None::<String>).unwrap();
+        let mut q = crate::batches::BatchesQuery::new(&client);
+        let _ = q
+            .with_before_enqueued_at(
+                OffsetDateTime::parse("2024-01-01T00:00:00Z", &Rfc3339).unwrap(),
+            )
+            .with_after_finished_at(
+                OffsetDateTime::parse("2023-02-03T13:02:38.369634Z", &Rfc3339).unwrap(),
+            );
+        let res = client.get_batches_with(&q).await.expect("request failed");
+        assert_eq!(res.total, 0);
+    }
+
+    #[test]
+    fn test_batch_stats_index_uids_keys() {
+        let stats: BatchStats = serde_json::from_str(
+            r#"{
+                "totalNbTasks": 3,
+                "indexUids": { "movies": 2, "movies_2024": 1, "old movies": 4 }
+            }"#,
+        )
+        .unwrap();
+
+        let uid: IndexUid = "movies_2024".parse().unwrap();
+        assert_eq!(stats.index_uids.get(&uid), Some(&1));
+        assert_eq!(stats.index_uids.get("movies"), Some(&2));
+        assert_eq!(stats.index_uids.get("old movies"), Some(&4));
+        assert_eq!(stats.index_uids.len(), 3);
+    }
+
+    #[test]
+    fn test_display_statuses() {
+        let statuses = Statuses {
+            enqueued: Some(1),
+            processing: Some(2),
+            succeeded: Some(5),
+            failed: Some(1),
+            canceled: Some(3),
+        };
+        assert_eq!(
+            statuses.to_string(),
+            "enqueued: 1, processing: 2, succeeded: 5, failed: 1, canceled: 3"
+        );
+
+        let statuses = Statuses {
+            succeeded: Some(5),
+            failed: Some(1),
+            canceled: Some(0),
+            ..Statuses::default()
+        };
+        assert_eq!(statuses.to_string(), "succeeded: 5, failed: 1");
+        assert_eq!(Statuses::default().to_string(), "");
+    }
+
+    #[test]
+    fn test_display_types() {
+        let types = Types {
+            document_addition_or_update: Some(1),
+            document_edition: Some(2),
+            document_deletion: Some(3),
+            settings_update: Some(4),
+            index_creation: Some(5),
+            index_deletion: Some(6),
+            index_update: Some(7),
+            index_swap: Some(8),
+            index_compaction: Some(9),
+            task_cancelation: Some(10),
+            task_deletion: Some(11),
+            dump_creation: Some(12),
+            snapshot_creation: Some(13),
+            upgrade_database: Some(14),
+            network_topology_change: Some(15),
+        };
+        assert_eq!(
+            types.to_string(),
+            "documentAdditionOrUpdate: 1, documentEdition: 2, documentDeletion: 3, settingsUpdate: 4, \
+             indexCreation: 5, indexDeletion: 6, indexUpdate: 7, indexSwap: 8, indexCompaction: 9, \
+             taskCancelation: 10, taskDeletion: 11, dumpCreation: 12, snapshotCreation: 13, \
+             upgradeDatabase: 14, networkTopologyChange: 15"
+        );
+
+        let types = Types {
+            document_addition_or_update: Some(3),
+            settings_update: Some(1),
+            index_swap: Some(0),
+            ..Types::default()
+        };
+        assert_eq!(
+            types.to_string(),
+            "documentAdditionOrUpdate: 3, settingsUpdate: 1"
+        );
+    }
+
+    #[test]
+    fn test_batch_without_batch_strategy() {
+        // as sent by Meilisearch before v1.15
+        let batch: Batch = serde_json::from_str(
+            r#"{
+                "uid": 1,
+                "enqueuedAt": "2024-10-11T11:49:53.000Z",
+                "startedAt": "2024-10-11T11:49:54.000Z",
+                "finishedAt": "2024-10-11T11:49:55.000Z",
+                "taskUids": [1]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(batch.uid, 1);
+        assert_eq!(batch.batch_strategy, None);
+    }
+
+    #[test]
+    fn test_batch_stats_without_progress_trace() {
+        let stats: BatchStats = serde_json::from_str(
+            r#"{
+                "totalNbTasks": 2,
+                "status": { "succeeded": 2 },
+                "types": { "documentAdditionOrUpdate": 2 },
+                "indexUids": { "movies": 2 }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(stats.total_nb_tasks, 2);
+        assert_eq!(stats.status.succeeded, Some(2));
+        assert_eq!(stats.types.document_addition_or_update, Some(2));
+        assert_eq!(stats.index_uids.get("movies"), Some(&2));
+        assert_eq!(stats.progress_trace, None);
+    }
+
+    #[test]
+    fn test_batch_stats_without_write_channel_congestion() {
+        let stats: BatchStats = serde_json::from_str(
+            r#"{
+                "totalNbTasks": 1,
+                "internalDatabaseSizes": { "externalDocumentsIds": "12.3 KiB (+1.1 KiB)" }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(stats.write_channel_congestion, None);
+        assert_eq!(
+            stats
+                .internal_database_sizes
+                .unwrap()
+                .get("externalDocumentsIds")
+                .map(String::as_str),
+            Some("12.3 KiB (+1.1 KiB)")
+        );
+    }
+
+    #[test]
+    fn test_batch_stats_without_internal_database_sizes() {
+        let stats: BatchStats = serde_json::from_str(
+            r#"{
+                "totalNbTasks": 1,
+                "writeChannelCongestion": { "attempts": 10, "blocking_attempts": 2, "blocking_ratio": 0.2 }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(stats.internal_database_sizes, None);
+        let congestion = stats.write_channel_congestion.unwrap();
+        assert_eq!(congestion.attempts, 10);
+        assert_eq!(congestion.blocking_attempts, 2);
+    }
+
+    #[test]
+    fn test_batches_results_without_from_and_next() {
+        let results: crate::batches::BatchesResults =
+            serde_json::from_str(r#"{"results":[],"limit":20,"total":0}"#).unwrap();
+
+        assert!(results.results.is_empty());
+        assert_eq!(results.from, None);
+        assert_eq!(results.next, None);
+    }
+
+    #[test]
+    fn test_add_statuses_and_types() {
+        let a = Statuses {
+            succeeded: Some(2),
+            ..Statuses::default()
+        };
+        let b = Statuses {
+            failed: Some(1),
+            ..Statuses::default()
+        };
+
+        let mut sum = a + b;
+        assert_eq!(
+            sum,
+            Statuses {
+                succeeded: Some(2),
+                failed: Some(1),
+                ..Statuses::default()
+            }
+        );
+
+        sum += a;
+        assert_eq!(sum.succeeded, Some(4));
+        assert_eq!(sum.enqueued, None);
+
+        let mut types = Types {
+            index_creation: Some(1),
+            ..Types::default()
+        };
+        types += Types {
+            index_creation: Some(2),
+            settings_update: Some(3),
+            ..Types::default()
+        };
+        assert_eq!(types.index_creation, Some(3));
+        assert_eq!(types.settings_update, Some(3));
+        assert_eq!(types.task_deletion, None);
+
+        types += Types {
+            index_creation: Some(u32::MAX),
+            ..Types::default()
+        };
+        assert_eq!(types.index_creation, Some(u32::MAX));
+    }
+
+    #[test]
+    fn test_batch_progress_current_step() {
+        let progress: BatchProgress = serde_json::from_str(
+            r#"{
+                "steps": [
+                    { "currentStep": "processing tasks", "finished": 0, "total": 2 },
+                    { "currentStep": "indexing", "finished": 2, "total": 3 }
+                ],
+                "percentage": 33.33
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            progress.current_step(),
+            Some(&BatchProgressStep {
+                current_step: "indexing".to_string(),
+                finished: 2,
+                total: 3,
+            })
+        );
+        assert_eq!(progress.current_step_name(), Some("indexing"));
+    }
+
+    #[test]
+    fn test_batch_progress_without_steps() {
+        let progress = BatchProgress {
+            steps: vec![],
+            percentage: 0.0,
+        };
+
+        assert_eq!(progress.current_step(), None);
+        assert_eq!(progress.current_step_name(), None);
+    }
+
+    #[test]
+    fn test_batch_progress_step_fraction() {
+        let step = |finished, total| BatchProgressStep {
+            current_step: "indexing".to_string(),
+            finished,
+            total,
+        };
+
+        assert_eq!(step(0, 0).fraction(), 0.0);
+        assert_eq!(step(1, 1).fraction(), 1.0);
+        assert_eq!(step(3, 10).fraction(), 0.3);
+        assert_eq!(step(4, 2).fraction(), 1.0);
+    }
+
+    #[test]
+    fn test_batch_uid_serializes_as_integer() {
+        let uid = BatchUid::from(42);
+
+        assert_eq!(serde_json::to_string(&uid).unwrap(), "42");
+        assert_eq!(serde_json::from_str::<BatchUid>("42").unwrap(), uid);
+        assert_eq!(uid.to_string(), "42");
+
+        assert_eq!(BatchUid::try_from(42i64), Ok(uid));
+        assert!(BatchUid::try_from(-1i64).is_err());
+        assert!(BatchUid::try_from(i64::from(u32::MAX) + 1).is_err());
+
+        let batch: Batch = serde_json::from_str(r#"{ "uid": 42 }"#).unwrap();
+        assert_eq!(batch.uid, uid);
+        assert_eq!(BatchUid::from(&batch), uid);
+    }
 }
@@ -1,3 +1,5 @@
+use futures_core::Stream;
+use futures_util::{stream, StreamExt, TryStreamExt};
 use serde::de::Error as SerdeError;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::{json, Value};
@@ -11,8 +13,9 @@ use crate::{
     network::{NetworkState, NetworkUpdate},
     request::*,
     search::*,
+    settings::Settings,
     task_info::TaskInfo,
-    tasks::{Task, TasksCancelQuery, TasksDeleteQuery, TasksResults, TasksSearchQuery},
+    tasks::{Task, TaskUid, TasksCancelQuery, TasksDeleteQuery, TasksResults, TasksSearchQuery},
     utils::SleepBackend,
     webhooks::{WebhookCreate, WebhookInfo, WebhookList, WebhookUpdate},
     DefaultHttpClient,
@@ -33,6 +36,35 @@ pub struct SwapIndexes {
     pub rename: Option<bool>,
 }
 
+impl SwapIndexes {
+    /// Create a swap between the indexes `a` and `b`.
+    ///
+    /// Returns [`Error::InvalidArgument`] when both uids are the same, since an index can't be swapped with itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::SwapIndexes;
+    /// let swap = SwapIndexes::new("movies", "movies_new").unwrap();
+    ///
+    /// assert_eq!(swap.indexes, ("movies".to_string(), "movies_new".to_string()));
+    /// assert!(SwapIndexes::new("movies", "movies").is_err());
+    /// ```
+    pub fn new(a: impl Into<String>, b: impl Into<String>) -> Result<SwapIndexes, Error> {
+        let (a, b) = (a.into(), b.into());
+        if a == b {
+            return Err(Error::InvalidArgument(format!(
+                "the index `{a}` can't be swapped with itself"
+            )));
+        }
+
+        Ok(SwapIndexes {
+            indexes: (a, b),
+            rename: None,
+        })
+    }
+}
+
 #[cfg(feature = "reqwest")]
 impl Client {
     /// Create a client using the specified server.
@@ -64,6 +96,126 @@ impl Client {
             http_client,
         })
     }
+
+    /// Create a client without checking that the server is reachable.
+    ///
+    /// Same as [`Client::new`], see [`Client::try_connect`] to fail fast when the server can't be reached.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    ///
+    /// let client = Client::new_unchecked(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// ```
+    pub fn new_unchecked(
+        host: impl Into<String>,
+        api_key: Option<impl Into<String>>,
+    ) -> Result<Client, Error> {
+        Client::new(host, api_key)
+    }
+
+    /// Create a client and check that the server is reachable and healthy.
+    ///
+    /// Returns an error if `GET /health` fails or doesn't answer with a `200`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// let client = Client::try_connect(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn try_connect(base_url: &str, api_key: Option<&str>) -> Result<Client, Error> {
+        let client = Client::new(base_url, api_key)?;
+        client.health().await?;
+
+        Ok(client)
+    }
+
+    /// Retry the requests failing before reaching Meilisearch according to the given [`RetryPolicy`](crate::retry::RetryPolicy).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use meilisearch_sdk::{client::*, retry::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY))
+    ///     .unwrap()
+    ///     .with_retry_policy(Box::new(
+    ///         ExponentialBackoffRetryPolicy::new(3, Duration::from_millis(100), 2.0).unwrap(),
+    ///     ));
+    /// ```
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: Box<dyn crate::retry::RetryPolicy>) -> Client {
+        self.http_client = self.http_client.with_retry_policy(retry_policy);
+        self
+    }
+
+    /// Also retry the `POST`, `PUT` and `PATCH` requests that failed after connecting to Meilisearch.
+    ///
+    /// By default, only the `GET` and `DELETE` requests, and the requests that couldn't connect to Meilisearch, are retried.
+    /// **The other requests may have been processed by Meilisearch, retrying them can enqueue the same task twice.**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use meilisearch_sdk::{client::*, retry::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY))
+    ///     .unwrap()
+    ///     .with_retry_policy(Box::new(
+    ///         ExponentialBackoffRetryPolicy::new(3, Duration::from_millis(100), 2.0).unwrap(),
+    ///     ))
+    ///     .with_non_idempotent_retries(true);
+    /// ```
+    #[must_use]
+    pub fn with_non_idempotent_retries(mut self, retry_non_idempotent: bool) -> Client {
+        self.http_client = self
+            .http_client
+            .with_non_idempotent_retries(retry_non_idempotent);
+        self
+    }
+
+    /// Give up on the requests that didn't get an answer after `timeout`.
+    ///
+    /// They fail with [`Error::RequestTimeout`], which is retried by the [`RetryPolicy`](crate::retry::RetryPolicy) if one is set
+    /// and the request is a `GET` or a `DELETE`. The other requests may have been processed by Meilisearch before timing out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use meilisearch_sdk::client::*;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY))
+    ///     .unwrap()
+    ///     .with_request_timeout(Duration::from_secs(5));
+    /// ```
+    #[must_use]
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Client {
+        self.http_client = self.http_client.with_request_timeout(timeout);
+        self
+    }
 }
 
 impl<Http: HttpClient> Client<Http> {
@@ -123,6 +275,8 @@ impl<Http: HttpClient> Client<Http> {
         &self,
         body: &MultiSearchQuery<'_, '_, Http>,
     ) -> Result<MultiSearchResponse<T>, Error> {
+        body.queries.iter().try_for_each(|query| query.validate())?;
+
         self.http_client
             .request::<(), &MultiSearchQuery<Http>, MultiSearchResponse<T>>(
                 &format!("{}/multi-search", &self.host),
@@ -132,12 +286,31 @@ impl<Http: HttpClient> Client<Http> {
             .await
     }
 
+    pub async fn execute_multi_search_query_per_query<
+        T: 'static + DeserializeOwned + Send + Sync,
+    >(
+        &self,
+        body: &MultiSearchQuery<'_, '_, Http>,
+    ) -> Result<MultiSearchResults<T>, Error> {
+        body.queries.iter().try_for_each(|query| query.validate())?;
+
+        self.http_client
+            .request::<(), &MultiSearchQuery<Http>, MultiSearchResults<T>>(
+                &format!("{}/multi-search", &self.host),
+                Method::Post { body, query: () },
+                200,
+            )
+            .await
+    }
+
     pub async fn execute_federated_multi_search_query<
         T: 'static + DeserializeOwned + Send + Sync,
     >(
         &self,
         body: &FederatedMultiSearchQuery<'_, '_, Http>,
     ) -> Result<FederatedMultiSearchResponse<T>, Error> {
+        body.queries.iter().try_for_each(|query| query.validate())?;
+
         self.http_client
             .request::<(), &FederatedMultiSearchQuery<Http>, FederatedMultiSearchResponse<T>>(
                 &format!("{}/multi-search", &self.host),
@@ -226,6 +399,12 @@ impl<Http: HttpClient> Client<Http> {
         &self.host
     }
 
+    /// Alias for [`Client::get_host`].
+    #[must_use]
+    pub fn base_url(&self) -> &str {
+        self.get_host()
+    }
+
     /// Return the api key associated with this index.
     ///
     /// # Example
@@ -242,6 +421,12 @@ impl<Http: HttpClient> Client<Http> {
         self.api_key.as_deref()
     }
 
+    /// Alias for [`Client::get_api_key`].
+    #[must_use]
+    pub fn api_key(&self) -> Option<&str> {
+        self.get_api_key()
+    }
+
     /// List all [Indexes](Index) with query parameters and return values as instances of [Index].
     ///
     /// # Example
@@ -421,8 +606,8 @@ impl<Http: HttpClient> Client<Http> {
     }
 
     /// Create a corresponding object of an [Index] without any check or doing an HTTP call.
-    pub fn index(&self, uid: impl Into<String>) -> Index<Http> {
-        Index::new(uid, self.clone())
+    pub fn index(&self, uid: impl Into<IndexUid>) -> Index<Http> {
+        Index::new(uid.into(), self.clone())
     }
 
     /// Create an [Index].
@@ -473,6 +658,100 @@ impl<Http: HttpClient> Client<Http> {
             .await
     }
 
+    /// Create an [Index] and return it once the creation task succeeded.
+    ///
+    /// Returns the error of the creation task if it failed, or [`Error::Timeout`] if it wasn't processed within `timeout`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let index = client
+    ///     .create_index_and_wait("create_index_and_wait", Some("id"), Duration::from_secs(5))
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert_eq!(index.primary_key.as_deref(), Some("id"));
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn create_index_and_wait(
+        &self,
+        uid: impl AsRef<str>,
+        primary_key: Option<&str>,
+        timeout: Duration,
+    ) -> Result<Index<Http>, Error> {
+        self.create_index(uid.as_ref(), primary_key)
+            .await?
+            .wait_for_success(self, timeout)
+            .await?;
+
+        let mut index = self.index(uid.as_ref());
+        index.fetch_info().await?;
+        Ok(index)
+    }
+
+    /// Create an [Index], apply the given [`Settings`] to it and return it once both tasks succeeded.
+    ///
+    /// `timeout` is applied to each of the two tasks separately, so this can wait up to twice `timeout`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let settings = Settings::new().with_filterable_attributes(["genre"]);
+    ///
+    /// let index = client
+    ///     .create_index_with_settings(
+    ///         "create_index_with_settings",
+    ///         Some("id"),
+    ///         &settings,
+    ///         Duration::from_secs(5),
+    ///     )
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert_eq!(index.as_ref(), "create_index_with_settings");
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn create_index_with_settings(
+        &self,
+        uid: impl AsRef<str>,
+        primary_key: Option<&str>,
+        settings: &Settings,
+        timeout: Duration,
+    ) -> Result<Index<Http>, Error> {
+        self.create_index(uid.as_ref(), primary_key)
+            .await?
+            .wait_for_success(self, timeout)
+            .await?;
+
+        let mut index = self.index(uid.as_ref());
+        index
+            .set_settings(settings)
+            .await?
+            .wait_for_success(self, timeout)
+            .await?;
+
+        index.fetch_info().await?;
+        Ok(index)
+    }
+
     /// Delete an index from its UID.
     ///
     /// To delete an [Index], use the [`Index::delete`] method.
@@ -486,6 +765,39 @@ impl<Http: HttpClient> Client<Http> {
             .await
     }
 
+    /// Delete an index from its UID and wait for the deletion to be processed.
+    ///
+    /// Returns the error of the deletion task if it failed, or [`Error::Timeout`] if it wasn't processed within `timeout`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("delete_index_and_wait", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// client
+    ///     .delete_index_and_wait("delete_index_and_wait", Duration::from_secs(5))
+    ///     .await
+    ///     .unwrap();
+    /// # });
+    /// ```
+    pub async fn delete_index_and_wait(
+        &self,
+        uid: impl AsRef<str>,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        self.delete_index(uid)
+            .await?
+            .wait_for_success(self, timeout)
+            .await
+    }
+
     /// Alias for [`Client::list_all_indexes`].
     pub async fn get_indexes(&self) -> Result<IndexesResults<Http>, Error> {
         self.list_all_indexes().await
@@ -512,6 +824,67 @@ impl<Http: HttpClient> Client<Http> {
         self.list_all_indexes_raw_with(indexes_query).await
     }
 
+    /// Alias for [`Client::get_raw_index`].
+    pub async fn get_index_raw(&self, uid: impl AsRef<str>) -> Result<Value, Error> {
+        self.get_raw_index(uid).await
+    }
+
+    /// List a page of [Indexes](Index), along with the pagination information.
+    ///
+    /// Shorthand for [`Client::list_all_indexes_with`] with only an `offset` and a `limit`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let indexes = client.list_indexes(0, 1).await.unwrap();
+    ///
+    /// assert_eq!(indexes.limit, 1);
+    /// # });
+    /// ```
+    pub async fn list_indexes(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> Result<IndexesResults<Http>, Error> {
+        let value = self.list_indexes_raw(offset, limit).await?;
+        self.parse_indexes_results_from_value(&value)
+    }
+
+    /// List a page of [Indexes](Index) and returns it as Json.
+    ///
+    /// Shorthand for [`Client::list_all_indexes_raw_with`] with only an `offset` and a `limit`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let json_indexes = client.list_indexes_raw(0, 1).await.unwrap();
+    ///
+    /// assert_eq!(json_indexes["limit"], 1);
+    /// # });
+    /// ```
+    pub async fn list_indexes_raw(&self, offset: u32, limit: u32) -> Result<Value, Error> {
+        let mut query = IndexesQuery::new(self);
+        query
+            .with_offset(offset as usize)
+            .with_limit(limit as usize);
+
+        self.list_all_indexes_raw_with(&query).await
+    }
+
     /// Swaps a list of two [Indexes](Index).
     ///
     /// # Example
@@ -705,6 +1078,47 @@ impl<Http: HttpClient> Client<Http> {
         Ok(keys)
     }
 
+    /// Stream all the API [Keys](Key) from Meilisearch, fetching them page by page.
+    ///
+    /// The stream stops after the first error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, errors::Error};
+    /// # use futures::StreamExt;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let keys: Vec<_> = client.stream_all_keys().collect().await;
+    ///
+    /// assert!(keys.iter().all(|key| key.is_ok()));
+    /// # });
+    /// ```
+    pub fn stream_all_keys(&self) -> impl Stream<Item = Result<Key, Error>> + '_ {
+        const KEYS_PAGE_SIZE: usize = 100;
+
+        stream::unfold(Some(0), move |offset| async move {
+            let offset = offset?;
+            let mut query = KeysQuery::new();
+            query.with_offset(offset).with_limit(KEYS_PAGE_SIZE);
+
+            match self.get_keys_with(&query).await {
+                Ok(page) => {
+                    let next = offset + page.results.len();
+                    let next =
+                        (!page.results.is_empty() && next < page.total as usize).then_some(next);
+                    Some((page.results.into_iter().map(Ok).collect::<Vec<_>>(), next))
+                }
+                Err(error) => Some((vec![Err(error)], None)),
+            }
+        })
+        .flat_map(stream::iter)
+    }
+
     /// Get one API [Key] from Meilisearch.
     ///
     /// See also [`Client::create_key`], [`Client::get_keys`], and the [meilisearch documentation](https://www.meilisearch.com/docs/reference/api/keys#get-one-key).
@@ -919,10 +1333,11 @@ impl<Http: HttpClient> Client<Http> {
     /// ```
     pub async fn wait_for_task(
         &self,
-        task_id: impl AsRef<u32>,
+        task_id: impl Into<TaskUid>,
         interval: Option<Duration>,
         timeout: Option<Duration>,
     ) -> Result<Task, Error> {
+        let task_id = task_id.into();
         let interval = interval.unwrap_or_else(|| Duration::from_millis(50));
         let timeout = timeout.unwrap_or_else(|| Duration::from_millis(5000));
 
@@ -930,7 +1345,7 @@ impl<Http: HttpClient> Client<Http> {
         let mut task_result: Result<Task, Error>;
 
         while timeout > elapsed_time {
-            task_result = self.get_task(&task_id).await;
+            task_result = self.get_task(task_id).await;
             match task_result {
                 Ok(status) => match status {
                     Task::Failed { .. } | Task::Succeeded { .. } => {
@@ -967,17 +1382,17 @@ impl<Http: HttpClient> Client<Http> {
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn get_task(&self, task_id: impl AsRef<u32>) -> Result<Task, Error> {
+    pub async fn get_task(&self, task_id: impl Into<TaskUid>) -> Result<Task, Error> {
         self.http_client
             .request::<(), (), Task>(
-                &format!("{}/tasks/{}", self.host, task_id.as_ref()),
+                &format!("{}/tasks/{}", self.host, task_id.into()),
                 Method::Get { query: () },
                 200,
             )
             .await
     }
 
-    /// Get all tasks with query parameters from the server.
+    /// Get a task from the server given a task id, returning `None` if it doesn't exist.
     ///
     /// # Example
     ///
@@ -989,20 +1404,52 @@ impl<Http: HttpClient> Client<Http> {
     /// #
     /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
     /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
-    /// let mut query = TasksSearchQuery::new(&client);
-    /// query.with_index_uids(["get_tasks_with"]);
+    /// let task = client.find_task(u32::MAX).await.unwrap();
     ///
-    /// let tasks = client.get_tasks_with(&query).await.unwrap();
+    /// assert!(task.is_none());
     /// # });
     /// ```
-    pub async fn get_tasks_with(
-        &self,
-        tasks_query: &TasksSearchQuery<'_, Http>,
-    ) -> Result<TasksResults, Error> {
-        let tasks = self
-            .http_client
-            .request::<&TasksSearchQuery<Http>, (), TasksResults>(
-                &format!("{}/tasks", self.host),
+    pub async fn find_task(&self, task_id: impl Into<TaskUid>) -> Result<Option<Task>, Error> {
+        match self.get_task(task_id).await {
+            Ok(task) => Ok(Some(task)),
+            Err(Error::Meilisearch(MeilisearchError {
+                error_code: ErrorCode::TaskNotFound,
+                ..
+            })) => Ok(None),
+            Err(Error::MeilisearchCommunication(MeilisearchCommunicationError {
+                status_code: 404,
+                ..
+            })) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get all tasks with query parameters from the server.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, tasks::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let mut query = TasksSearchQuery::new(&client);
+    /// query.with_index_uids(["get_tasks_with"]);
+    ///
+    /// let tasks = client.get_tasks_with(&query).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_tasks_with(
+        &self,
+        tasks_query: &TasksSearchQuery<'_, Http>,
+    ) -> Result<TasksResults, Error> {
+        let tasks = self
+            .http_client
+            .request::<&TasksSearchQuery<Http>, (), TasksResults>(
+                &format!("{}/tasks", self.host),
                 Method::Get { query: tasks_query },
                 200,
             )
@@ -1140,6 +1587,65 @@ impl<Http: HttpClient> Client<Http> {
         Ok(res)
     }
 
+    /// Stream all the [batches](crate::batches::Batch), fetching them page by page from the most recent one.
+    ///
+    /// The stream stops after the first error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::Client;
+    /// # use futures::StreamExt;
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let batches: Vec<_> = client.stream_all_batches().collect().await;
+    ///
+    /// assert!(batches.iter().all(|batch| batch.is_ok()));
+    /// # });
+    /// ```
+    pub fn stream_all_batches(
+        &self,
+    ) -> impl Stream<Item = Result<crate::batches::Batch, Error>> + '_ {
+        stream::unfold(Some(None), move |from: Option<Option<u32>>| async move {
+            let from = from?;
+            let mut query = crate::batches::BatchesQuery::new(self);
+            if let Some(from) = from {
+                let _ = query.with_from(from);
+            }
+
+            match self.get_batches_with(&query).await {
+                Ok(page) => {
+                    let next = page.next.filter(|_| !page.results.is_empty()).map(Some);
+                    Some((page.results.into_iter().map(Ok).collect::<Vec<_>>(), next))
+                }
+                Err(error) => Some((vec![Err(error)], None)),
+            }
+        })
+        .flat_map(stream::iter)
+    }
+
+    /// Get all the [batches](crate::batches::Batch), following the pagination until the oldest one.
+    ///
+    /// Built on [`Client::stream_all_batches`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::Client;
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let batches = client.get_all_batches().await.unwrap();
+    /// # let _ = batches;
+    /// # });
+    /// ```
+    pub async fn get_all_batches(&self) -> Result<Vec<crate::batches::Batch>, Error> {
+        self.stream_all_batches().try_collect().await
+    }
+
     /// List batches with pagination filters.
     ///
     /// # Example
@@ -1160,6 +1666,7 @@ impl<Http: HttpClient> Client<Http> {
         &self,
         query: &crate::batches::BatchesQuery<'_, Http>,
     ) -> Result<crate::batches::BatchesResults, Error> {
+        query.validate()?;
         let res = self
             .http_client
             .request::<&crate::batches::BatchesQuery<'_, Http>, (), crate::batches::BatchesResults>(
@@ -1186,7 +1693,11 @@ impl<Http: HttpClient> Client<Http> {
     /// # let _ = batch;
     /// # });
     /// ```
-    pub async fn get_batch(&self, uid: u32) -> Result<crate::batches::Batch, Error> {
+    pub async fn get_batch(
+        &self,
+        uid: impl Into<crate::batches::BatchUid>,
+    ) -> Result<crate::batches::Batch, Error> {
+        let uid = uid.into();
         let res = self
             .http_client
             .request::<(), (), crate::batches::Batch>(
@@ -1198,6 +1709,39 @@ impl<Http: HttpClient> Client<Http> {
         Ok(res)
     }
 
+    /// Get a single batch by its uid, returning `None` if it doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::Client;
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let batch = client.find_batch(u32::MAX).await.unwrap();
+    ///
+    /// assert!(batch.is_none());
+    /// # });
+    /// ```
+    pub async fn find_batch(
+        &self,
+        uid: impl Into<crate::batches::BatchUid>,
+    ) -> Result<Option<crate::batches::Batch>, Error> {
+        match self.get_batch(uid).await {
+            Ok(batch) => Ok(Some(batch)),
+            Err(Error::Meilisearch(MeilisearchError {
+                error_code: ErrorCode::BatchNotFound,
+                ..
+            })) => Ok(None),
+            Err(Error::MeilisearchCommunication(MeilisearchCommunicationError {
+                status_code: 404,
+                ..
+            })) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Generates a new tenant token.
     ///
     /// # Example
@@ -1220,7 +1764,7 @@ impl<Http: HttpClient> Client<Http> {
     pub fn generate_tenant_token(
         &self,
         api_key_uid: String,
-        search_rules: Value,
+        search_rules: impl Into<Value>,
         api_key: Option<&str>,
         expires_at: Option<OffsetDateTime>,
     ) -> Result<String, Error> {
@@ -1233,7 +1777,12 @@ impl<Http: HttpClient> Client<Http> {
             }
         };
 
-        crate::tenant_tokens::generate_tenant_token(api_key_uid, search_rules, api_key, expires_at)
+        crate::tenant_tokens::generate_tenant_token(
+            api_key_uid,
+            search_rules.into(),
+            api_key,
+            expires_at,
+        )
     }
 
     /// Get the current network state (/network).
@@ -1609,6 +2158,846 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_find_batch_returns_none_when_not_found() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        let mock = s
+            .mock("GET", "/batches/42")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"message":"Batch `42` not found.","code":"batch_not_found","type":"invalid_request","link":"https://docs.meilisearch.com/errors#batch_not_found"}"#)
+            .create_async()
+            .await;
+
+        assert!(client.find_batch(42).await?.is_none());
+
+        mock.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_find_task_returns_none_when_not_found() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        let mock = s
+            .mock("GET", "/tasks/42")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"message":"Task `42` not found.","code":"task_not_found","type":"invalid_request","link":"https://docs.meilisearch.com/errors#task_not_found"}"#)
+            .create_async()
+            .await;
+
+        assert!(client.find_task(42).await?.is_none());
+
+        mock.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_retries_connection_failures() -> Result<(), Error> {
+        use std::io::{Read, Write};
+
+        // nothing listens on the address until the server thread starts
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let url = format!("http://{addr}");
+        let server = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            let listener = std::net::TcpListener::bind(addr).unwrap();
+
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0; 1024];
+            let _ = stream.read(&mut buf).unwrap();
+            let body = r#"{"status":"available"}"#;
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        });
+
+        let client = Client::new(url, None::<String>)?.with_retry_policy(Box::new(
+            crate::retry::ExponentialBackoffRetryPolicy::new(20, Duration::from_millis(5), 2.0)?
+                .with_max_delay(Duration::from_millis(50)),
+        ));
+
+        let health = client.health().await?;
+        assert_eq!(health.status, "available");
+
+        server.join().unwrap();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retried_requests_send_the_whole_body_again() -> Result<(), Error> {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let read_request = |stream: &mut std::net::TcpStream| {
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                // the streamed body is chunked
+                while !request.ends_with(b"0\r\n\r\n") {
+                    let read = stream.read(&mut buf).unwrap();
+                    request.extend_from_slice(&buf[..read]);
+                }
+                String::from_utf8(request).unwrap()
+            };
+
+            // read the whole request but answer too late
+            let (mut stream, _) = listener.accept().unwrap();
+            let first = read_request(&mut stream);
+            std::thread::sleep(Duration::from_millis(400));
+            drop(stream);
+
+            let (mut stream, _) = listener.accept().unwrap();
+            let second = read_request(&mut stream);
+            let body = r#"{"taskUid":1,"indexUid":"movies","status":"enqueued","type":"indexCreation","enqueuedAt":"2024-10-11T11:49:53.000Z"}"#;
+            write!(
+                stream,
+                "HTTP/1.1 202 Accepted\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            (first, second)
+        });
+
+        let client = Client::new(url, None::<String>)?
+            .with_retry_policy(Box::new(crate::retry::ExponentialBackoffRetryPolicy::new(
+                2,
+                Duration::from_millis(1),
+                2.0,
+            )?))
+            .with_non_idempotent_retries(true)
+            .with_request_timeout(Duration::from_millis(300));
+
+        let task = client.create_index("movies", None).await?;
+        assert_eq!(task.get_task_uid(), 1);
+
+        let (first, second) = server.join().unwrap();
+        for request in [first, second] {
+            assert!(request.contains(r#""uid":"movies""#), "{}", request);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_doesnt_retry_sent_post_requests() -> Result<(), Error> {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            // drop the connection without answering, the request may have been processed
+            drop(listener.accept().unwrap());
+            listener
+        });
+
+        let client = Client::new(url, None::<String>)?
+            .with_retry_policy(Box::new(crate::retry::ExponentialBackoffRetryPolicy::new(
+                2,
+                Duration::from_millis(1),
+                2.0,
+            )?))
+            .with_request_timeout(Duration::from_secs(1));
+
+        let error = client.create_index("movies", None).await.unwrap_err();
+        assert!(matches!(error, Error::HttpError(_)), "{:?}", error);
+
+        let listener = server.join().unwrap();
+        listener.set_nonblocking(true).unwrap();
+        assert_eq!(
+            listener.accept().unwrap_err().kind(),
+            std::io::ErrorKind::WouldBlock
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "log")]
+    #[tokio::test]
+    async fn test_requests_are_logged() -> Result<(), Error> {
+        use std::cell::RefCell;
+
+        // the test runtime runs on the test thread, the records of the other tests stay on their own thread
+        thread_local! {
+            static LOGS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+        }
+
+        struct Logger;
+        impl log::Log for Logger {
+            fn enabled(&self, metadata: &log::Metadata) -> bool {
+                metadata.level() <= log::Level::Debug
+            }
+            fn log(&self, record: &log::Record) {
+                if record.target().starts_with("meilisearch_sdk") {
+                    LOGS.with(|logs| logs.borrow_mut().push(record.args().to_string()));
+                }
+            }
+            fn flush(&self) {}
+        }
+
+        // the logger can only be set once per process
+        let _ = log::set_logger(&Logger);
+        log::set_max_level(log::LevelFilter::Debug);
+
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let _mock = s
+            .mock("GET", "/health")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status":"available"}"#)
+            .create_async()
+            .await;
+
+        client.health().await?;
+
+        let url = format!("{}/health", s.url());
+        let logs: Vec<String> = LOGS.with(|logs| {
+            logs.borrow()
+                .iter()
+                .filter(|line| line.ends_with(&url))
+                .cloned()
+                .collect()
+        });
+        assert_eq!(
+            logs,
+            vec![format!("→ GET {}", url), format!("← 200 {}", url)]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout() -> Result<(), Error> {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            // accept the connection but answer too late
+            let (stream, _) = listener.accept().unwrap();
+            std::thread::sleep(Duration::from_millis(500));
+            drop(stream);
+        });
+
+        let client =
+            Client::new(&url, None::<String>)?.with_request_timeout(Duration::from_millis(50));
+
+        match client.health().await {
+            Err(Error::RequestTimeout {
+                url: timed_out,
+                elapsed,
+            }) => {
+                assert_eq!(timed_out, format!("{}/health", url));
+                assert!(elapsed >= Duration::from_millis(50));
+            }
+            other => panic!("expected a request timeout, got {:?}", other),
+        }
+
+        server.join().unwrap();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout_while_reading_the_body() -> Result<(), Error> {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            // answer the headers in time but not the whole body
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0; 1024];
+            let _ = stream.read(&mut buf).unwrap();
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 100\r\n\r\n{{"
+            )
+            .unwrap();
+            stream.flush().unwrap();
+            std::thread::sleep(Duration::from_millis(500));
+            drop(stream);
+        });
+
+        let client =
+            Client::new(&url, None::<String>)?.with_request_timeout(Duration::from_millis(50));
+
+        match client.health().await {
+            Err(Error::RequestTimeout { url: timed_out, .. }) => {
+                assert_eq!(timed_out, format!("{}/health", url));
+            }
+            other => panic!("expected a request timeout, got {:?}", other),
+        }
+
+        server.join().unwrap();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_try_connect() {
+        let mut s = mockito::Server::new_async().await;
+
+        let healthy = s
+            .mock("GET", "/health")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status":"available"}"#)
+            .create_async()
+            .await;
+        assert!(Client::try_connect(&s.url(), None).await.is_ok());
+        healthy.assert_async().await;
+        healthy.remove_async().await;
+
+        let _unhealthy = s
+            .mock("GET", "/health")
+            .with_status(503)
+            .create_async()
+            .await;
+        assert!(Client::try_connect(&s.url(), None).await.is_err());
+
+        // nothing listens on the discard port
+        assert!(Client::try_connect("http://127.0.0.1:9", None)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_index_checks_existence() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        let _movies = s
+            .mock("GET", "/indexes/movies")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"uid":"movies","primaryKey":"id","createdAt":"2024-10-11T11:49:53.000Z","updatedAt":"2024-10-12T11:49:53.000Z"}"#)
+            .create_async()
+            .await;
+        let _missing = s
+            .mock("GET", "/indexes/missing")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"message":"Index `missing` not found.","code":"index_not_found","type":"invalid_request","link":"https://docs.meilisearch.com/errors#index_not_found"}"#)
+            .create_async()
+            .await;
+
+        let movies = client.get_index("movies").await?;
+        assert_eq!(movies.uid, "movies");
+        assert_eq!(movies.primary_key.as_deref(), Some("id"));
+        assert!(movies.created_at.is_some());
+        assert!(movies.updated_at.is_some());
+
+        let missing = client.get_index("missing").await;
+        assert!(matches!(
+            missing,
+            Err(Error::Meilisearch(MeilisearchError {
+                error_code: ErrorCode::IndexNotFound,
+                ..
+            }))
+        ));
+        Ok(())
+    }
+
+    const INDEX_CREATION_TASK: &str = r#"{"enqueuedAt":"2022-02-03T13:02:38.369634Z","indexUid":"movies","status":"enqueued","type":"indexCreation","taskUid":1}"#;
+    const SUCCEEDED_INDEX_CREATION_TASK: &str = r#"{"details":{"primaryKey":"id"},"duration":"PT0.1S","enqueuedAt":"2022-02-03T13:02:38.369634Z","finishedAt":"2022-02-03T13:02:38.469634Z","indexUid":"movies","startedAt":"2022-02-03T13:02:38.379634Z","status":"succeeded","type":"indexCreation","uid":1}"#;
+
+    #[tokio::test]
+    async fn test_create_index_with_settings() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        let create = s
+            .mock("POST", "/indexes")
+            .match_body(mockito::Matcher::Json(
+                json!({"uid": "movies", "primaryKey": "id"}),
+            ))
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(INDEX_CREATION_TASK)
+            .create_async()
+            .await;
+        let _creation_task = s
+            .mock("GET", "/tasks/1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(SUCCEEDED_INDEX_CREATION_TASK)
+            .create_async()
+            .await;
+        let update = s
+            .mock("PATCH", "/indexes/movies/settings")
+            .match_body(mockito::Matcher::Json(
+                json!({"filterableAttributes": ["genre"]}),
+            ))
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"enqueuedAt":"2022-02-03T13:02:39.369634Z","indexUid":"movies","status":"enqueued","type":"settingsUpdate","taskUid":2}"#)
+            .create_async()
+            .await;
+        let _update_task = s
+            .mock("GET", "/tasks/2")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"details":{"filterableAttributes":["genre"]},"duration":"PT0.1S","enqueuedAt":"2022-02-03T13:02:39.369634Z","finishedAt":"2022-02-03T13:02:39.469634Z","indexUid":"movies","startedAt":"2022-02-03T13:02:39.379634Z","status":"succeeded","type":"settingsUpdate","uid":2}"#)
+            .create_async()
+            .await;
+        let _index = s
+            .mock("GET", "/indexes/movies")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"uid":"movies","primaryKey":"id","createdAt":"2022-02-03T13:02:38.469634Z","updatedAt":"2022-02-03T13:02:39.469634Z"}"#)
+            .create_async()
+            .await;
+
+        let settings = Settings::new().with_filterable_attributes(["genre"]);
+        let index = client
+            .create_index_with_settings("movies", Some("id"), &settings, Duration::from_secs(1))
+            .await?;
+
+        create.assert_async().await;
+        update.assert_async().await;
+        assert_eq!(index.uid, "movies");
+        assert_eq!(index.primary_key.as_deref(), Some("id"));
+        assert!(index.updated_at.is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_index_and_wait() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        let create = s
+            .mock("POST", "/indexes")
+            .match_body(mockito::Matcher::Json(
+                json!({"uid": "movies", "primaryKey": "id"}),
+            ))
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(INDEX_CREATION_TASK)
+            .create_async()
+            .await;
+        let _task = s
+            .mock("GET", "/tasks/1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(SUCCEEDED_INDEX_CREATION_TASK)
+            .create_async()
+            .await;
+        let index = s
+            .mock("GET", "/indexes/movies")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"uid":"movies","primaryKey":"id","createdAt":"2022-02-03T13:02:38.469634Z","updatedAt":"2022-02-03T13:02:38.469634Z"}"#)
+            .create_async()
+            .await;
+
+        let movies = client
+            .create_index_and_wait("movies", Some("id"), Duration::from_secs(1))
+            .await?;
+
+        create.assert_async().await;
+        index.assert_async().await;
+        assert_eq!(movies.uid, "movies");
+        assert_eq!(movies.primary_key.as_deref(), Some("id"));
+        assert!(movies.created_at.is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete_index_and_wait() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        let delete = s
+            .mock("DELETE", "/indexes/movies")
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"enqueuedAt":"2022-02-03T13:02:38.369634Z","indexUid":"movies","status":"enqueued","type":"indexDeletion","taskUid":1}"#)
+            .create_async()
+            .await;
+        let task = s
+            .mock("GET", "/tasks/1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"details":{"deletedDocuments":0},"duration":"PT0.1S","enqueuedAt":"2022-02-03T13:02:38.369634Z","finishedAt":"2022-02-03T13:02:38.469634Z","indexUid":"movies","startedAt":"2022-02-03T13:02:38.379634Z","status":"succeeded","type":"indexDeletion","uid":1}"#)
+            .create_async()
+            .await;
+
+        client
+            .delete_index_and_wait("movies", Duration::from_secs(1))
+            .await?;
+
+        delete.assert_async().await;
+        task.remove_async().await;
+
+        let _failed_task = s
+            .mock("GET", "/tasks/1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"details":{"deletedDocuments":0},"duration":"PT0.1S","enqueuedAt":"2022-02-03T13:02:38.369634Z","finishedAt":"2022-02-03T13:02:38.469634Z","indexUid":"movies","startedAt":"2022-02-03T13:02:38.379634Z","status":"failed","type":"indexDeletion","uid":1,"error":{"message":"Index `movies` not found.","code":"index_not_found","type":"invalid_request","link":"https://docs.meilisearch.com/errors#index_not_found"}}"#)
+            .create_async()
+            .await;
+
+        let error = client
+            .delete_index_and_wait("movies", Duration::from_secs(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Meilisearch(MeilisearchError {
+                error_code: ErrorCode::IndexNotFound,
+                ..
+            })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_swap_indexes_new() {
+        let swap = SwapIndexes::new("movies", "movies_new").unwrap();
+        assert_eq!(
+            swap.indexes,
+            ("movies".to_string(), "movies_new".to_string())
+        );
+        assert_eq!(swap.rename, None);
+
+        assert!(matches!(
+            SwapIndexes::new("movies", "movies"),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_list_indexes_keeps_pagination() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        let mock = s
+            .mock("GET", "/indexes")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("offset".into(), "2".into()),
+                mockito::Matcher::UrlEncoded("limit".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"results":[{"uid":"movies","primaryKey":"id","createdAt":"2024-10-11T11:49:53.000Z","updatedAt":"2024-10-11T11:49:53.000Z"}],"offset":2,"limit":1,"total":5}"#)
+            .create_async()
+            .await;
+
+        let indexes = client.list_indexes(2, 1).await?;
+
+        mock.assert_async().await;
+        assert_eq!(indexes.offset, 2);
+        assert_eq!(indexes.limit, 1);
+        assert_eq!(indexes.total, 5);
+        assert_eq!(indexes.results.len(), 1);
+        assert_eq!(indexes.results[0].uid, "movies");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_all_indexes_sends_no_pagination() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        // Meilisearch applies its own defaults of 0 and 20
+        let mock = s
+            .mock("GET", "/indexes")
+            .match_query(mockito::Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"results":[],"offset":0,"limit":20,"total":0}"#)
+            .create_async()
+            .await;
+
+        let indexes = client.list_all_indexes().await?;
+
+        mock.assert_async().await;
+        assert_eq!(indexes.offset, 0);
+        assert_eq!(indexes.limit, 20);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_stats() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        let mock = s
+            .mock("GET", "/stats")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "databaseSize": 447819776,
+                    "usedDatabaseSize": 196608,
+                    "lastUpdate": "2019-11-15T11:15:22.092896Z",
+                    "indexes": {
+                        "movies": {
+                            "numberOfDocuments": 19654,
+                            "numberOfEmbeddedDocuments": 1,
+                            "numberOfEmbeddings": 1,
+                            "rawDocumentDbSize": 5_000_000_000u64,
+                            "avgDocumentSize": 1024,
+                            "isIndexing": false,
+                            "fieldDistribution": { "title": 19654, "poster": 19654 }
+                        },
+                        "books": {
+                            "numberOfDocuments": 5,
+                            "numberOfEmbeddedDocuments": 0,
+                            "numberOfEmbeddings": 0,
+                            "avgDocumentSize": 0,
+                            "isIndexing": true,
+                            "fieldDistribution": {}
+                        }
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let stats = client.get_stats().await?;
+
+        mock.assert_async().await;
+        assert_eq!(stats.database_size, 447819776);
+        assert_eq!(stats.used_database_size, 196608);
+        assert!(stats.last_update.is_some());
+        assert_eq!(stats.indexes.len(), 2);
+
+        let movies = &stats.indexes["movies"];
+        assert_eq!(movies.number_of_documents, 19654);
+        assert_eq!(movies.raw_document_db_size, Some(5_000_000_000));
+        assert!(!movies.is_indexing);
+        assert_eq!(movies.field_distribution["title"], 19654);
+
+        let books = &stats.indexes["books"];
+        assert_eq!(books.number_of_documents, 5);
+        assert_eq!(books.raw_document_db_size, None);
+        assert!(books.is_indexing);
+        assert!(books.field_distribution.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_batches_uses_default_limit() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        // no limit is sent, so Meilisearch applies its default of 20
+        let mock = s
+            .mock("GET", "/batches")
+            .match_query(mockito::Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"results":[{"uid":1}],"total":1,"limit":20,"from":1,"next":null}"#)
+            .create_async()
+            .await;
+
+        let batches = client.get_batches().await?;
+
+        mock.assert_async().await;
+        assert_eq!(batches.limit, 20);
+        assert_eq!(batches.results.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_all_batches_follows_next() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        let first_page = s
+            .mock("GET", "/batches")
+            .match_query(mockito::Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"results":[{"uid":3},{"uid":2}],"total":3,"limit":2,"from":3,"next":1}"#)
+            .create_async()
+            .await;
+        let second_page = s
+            .mock("GET", "/batches")
+            .match_query(mockito::Matcher::UrlEncoded("from".into(), "1".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"results":[{"uid":1}],"total":3,"limit":2,"from":1,"next":null}"#)
+            .create_async()
+            .await;
+
+        let batches = client.get_all_batches().await?;
+
+        first_page.assert_async().await;
+        second_page.assert_async().await;
+        assert_eq!(
+            batches.iter().map(|batch| batch.uid).collect::<Vec<_>>(),
+            vec![3, 2, 1]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_base_url() {
+        let client = Client::new("http://localhost:7700", None::<String>).unwrap();
+        assert_eq!(client.base_url(), "http://localhost:7700");
+        assert_eq!(client.base_url(), client.get_host());
+    }
+
+    #[test]
+    fn test_api_key() {
+        let client = Client::new("http://localhost:7700", Some("masterKey")).unwrap();
+        assert_eq!(client.api_key(), Some("masterKey"));
+
+        let client = Client::new("http://localhost:7700", None::<String>).unwrap();
+        assert_eq!(client.api_key(), None);
+    }
+
+    #[tokio::test]
+    async fn test_raw_index_getters() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        let index_body = r#"{"uid":"movies","primaryKey":"id","createdAt":"2024-10-11T11:49:53.000Z","updatedAt":"2024-10-11T11:49:53.000Z","someNewField":true}"#;
+        let index_mock = s
+            .mock("GET", "/indexes/movies")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(index_body)
+            .create_async()
+            .await;
+        let list_mock = s
+            .mock("GET", "/indexes")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("offset".into(), "10".into()),
+                mockito::Matcher::UrlEncoded("limit".into(), "5".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(
+                r#"{{"results":[{index_body}],"offset":10,"limit":5,"total":11}}"#
+            ))
+            .create_async()
+            .await;
+
+        let index = client.get_index_raw("movies").await?;
+        assert_eq!(index["someNewField"], json!(true));
+
+        let indexes = client.list_indexes_raw(10, 5).await?;
+        assert_eq!(indexes["offset"], json!(10));
+        assert_eq!(indexes["results"][0]["uid"], json!("movies"));
+
+        index_mock.assert_async().await;
+        list_mock.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stream_all_keys_fetches_every_page() {
+        use futures::StreamExt;
+        use mockito::Matcher;
+
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let key = |uid: &str| {
+            serde_json::json!({
+                "actions": ["search"],
+                "createdAt": "2024-10-11T11:49:53.000Z",
+                "updatedAt": "2024-10-11T11:49:53.000Z",
+                "description": null,
+                "name": null,
+                "expiresAt": null,
+                "indexes": ["*"],
+                "key": format!("key-{uid}"),
+                "uid": uid,
+            })
+        };
+
+        let first_page = s
+            .mock("GET", "/keys")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("offset".into(), "0".into()),
+                Matcher::UrlEncoded("limit".into(), "100".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "results": [key("1"), key("2")],
+                    "offset": 0,
+                    "limit": 100,
+                    "total": 3
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let second_page = s
+            .mock("GET", "/keys")
+            .match_query(Matcher::UrlEncoded("offset".into(), "2".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "results": [key("3")],
+                    "offset": 2,
+                    "limit": 100,
+                    "total": 3
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let keys: Vec<Key> = client
+            .stream_all_keys()
+            .map(|key| key.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(
+            keys.iter().map(|key| key.uid.as_str()).collect::<Vec<_>>(),
+            ["1", "2", "3"]
+        );
+        first_page.assert_async().await;
+        second_page.assert_async().await;
+    }
+
+    // the requests can't be spawned when they aren't `Send`
+    #[cfg(not(feature = "futures-unsend"))]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_cloned_clients_can_be_used_concurrently() {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        let health = s
+            .mock("GET", "/health")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status":"available"}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let index = client.index("movies").clone();
+        assert_eq!(index.uid, "movies");
+
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let client = client.clone();
+                tokio::spawn(async move { client.health().await })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.await.unwrap().unwrap().status, "available");
+        }
+        health.assert_async().await;
+    }
+
     use big_s::S;
     use time::OffsetDateTime;
 
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::convert::TryFrom;
 
 /// Derive the [`IndexConfig`] trait.
 ///
@@ -66,6 +67,117 @@ pub trait IndexConfig {
     async fn generate_index<Http: HttpClient>(client: &Client<Http>) -> Result<Index<Http>, Task>;
 }
 
+/// The primary key value of a document.
+///
+/// Meilisearch accepts both integers and strings as document ids.
+/// When displayed, integers are written as is and strings are percent-encoded so they can be embedded in a url path.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::documents::DocumentId;
+/// assert_eq!(DocumentId::from(42).to_string(), "42");
+/// assert_eq!(DocumentId::from("Interstellar").to_string(), "Interstellar");
+/// assert_eq!(DocumentId::from("a b").to_string(), "a%20b");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DocumentId {
+    Integer(i64),
+    String(String),
+}
+
+impl std::fmt::Display for DocumentId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DocumentId::Integer(id) => write!(f, "{id}"),
+            DocumentId::String(id) => {
+                for byte in id.bytes() {
+                    if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+                        write!(f, "{}", byte as char)?;
+                    } else {
+                        write!(f, "%{byte:02X}")?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl From<i64> for DocumentId {
+    fn from(id: i64) -> Self {
+        DocumentId::Integer(id)
+    }
+}
+
+impl From<i32> for DocumentId {
+    fn from(id: i32) -> Self {
+        DocumentId::Integer(id.into())
+    }
+}
+
+impl From<u32> for DocumentId {
+    fn from(id: u32) -> Self {
+        DocumentId::Integer(id.into())
+    }
+}
+
+/// Ids above `i64::MAX` are sent as strings.
+impl From<u64> for DocumentId {
+    fn from(id: u64) -> Self {
+        match i64::try_from(id) {
+            Ok(id) => DocumentId::Integer(id),
+            Err(_) => DocumentId::String(id.to_string()),
+        }
+    }
+}
+
+/// Ids above `i64::MAX` are sent as strings.
+impl From<usize> for DocumentId {
+    fn from(id: usize) -> Self {
+        DocumentId::from(id as u64)
+    }
+}
+
+macro_rules! impl_from_ref {
+    ($($ty:ty),*) => {
+        $(
+            impl From<&$ty> for DocumentId {
+                fn from(id: &$ty) -> Self {
+                    DocumentId::from(*id)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_ref!(i64, i32, u32, u64, usize, &str);
+
+impl From<&DocumentId> for DocumentId {
+    fn from(id: &DocumentId) -> Self {
+        id.clone()
+    }
+}
+
+impl From<String> for DocumentId {
+    fn from(id: String) -> Self {
+        DocumentId::String(id)
+    }
+}
+
+impl From<&String> for DocumentId {
+    fn from(id: &String) -> Self {
+        DocumentId::String(id.clone())
+    }
+}
+
+impl From<&str> for DocumentId {
+    fn from(id: &str) -> Self {
+        DocumentId::String(id.to_string())
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct DocumentsResults<T> {
     pub results: Vec<T>,
@@ -156,7 +268,7 @@ impl<'a, Http: HttpClient> DocumentQuery<'a, Http> {
     /// # });
     pub async fn execute<T: DeserializeOwned + 'static + Send + Sync>(
         &self,
-        document_id: &str,
+        document_id: impl Into<DocumentId>,
     ) -> Result<T, Error> {
         self.index.get_document_with::<T>(document_id, self).await
     }
@@ -207,8 +319,7 @@ pub struct DocumentsQuery<'a, Http: HttpClient> {
     ///
     /// When `ids` is provided, the SDK will call the `/documents/fetch` endpoint with a POST request.
     ///
-    /// Note: IDs are represented as strings to keep consistency with [`Index::get_document`]. If your IDs
-    /// are numeric, pass them as strings (e.g., `"1"`, `"2"`).
+    /// Note: IDs are represented as strings. If your IDs are numeric, pass them as strings (e.g., `"1"`, `"2"`).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ids: Option<Vec<&'a str>>,
 }
@@ -453,6 +564,57 @@ mod tests {
         video_id: u64,
     }
 
+    #[test]
+    fn test_integer_document_id() {
+        let id = DocumentId::from(25684);
+
+        assert_eq!(id, DocumentId::Integer(25684));
+        assert_eq!(id.to_string(), "25684");
+        assert_eq!(serde_json::to_value(&id).unwrap(), serde_json::json!(25684));
+        assert_eq!(
+            serde_json::from_value::<DocumentId>(serde_json::json!(25684)).unwrap(),
+            id
+        );
+    }
+
+    #[test]
+    fn test_string_document_id() {
+        let id = DocumentId::from("Interstellar");
+
+        assert_eq!(id, DocumentId::String("Interstellar".to_string()));
+        assert_eq!(id, DocumentId::from(String::from("Interstellar")));
+        assert_eq!(id.to_string(), "Interstellar");
+        assert_eq!(DocumentId::from("a b/c").to_string(), "a%20b%2Fc");
+        assert_eq!(
+            serde_json::to_value(&id).unwrap(),
+            serde_json::json!("Interstellar")
+        );
+        assert_eq!(
+            serde_json::from_value::<DocumentId>(serde_json::json!("Interstellar")).unwrap(),
+            id
+        );
+    }
+
+    #[test]
+    fn test_document_id_from_unsigned_and_borrowed_ids() {
+        assert_eq!(DocumentId::from(42u64), DocumentId::Integer(42));
+        assert_eq!(DocumentId::from(42usize), DocumentId::Integer(42));
+        assert_eq!(
+            DocumentId::from(u64::MAX),
+            DocumentId::String(u64::MAX.to_string())
+        );
+
+        assert_eq!(DocumentId::from(&42), DocumentId::Integer(42));
+        assert_eq!(DocumentId::from(&42u64), DocumentId::Integer(42));
+        assert_eq!(
+            DocumentId::from(&"Interstellar"),
+            DocumentId::from("Interstellar")
+        );
+
+        let id = DocumentId::from("Interstellar");
+        assert_eq!(DocumentId::from(&id), id);
+    }
+
     async fn setup_test_index(client: &Client, index: &Index) -> Result<(), Error> {
         let t0 = index
             .add_documents(
@@ -743,11 +905,20 @@ Hint: It might not be working because you're not up to date with the Meilisearch
         assert_eq!(movie_settings.searchable_attributes.unwrap(), ["title"]);
         assert!(video_settings.searchable_attributes.unwrap().is_empty());
 
+        use crate::settings::DisplayedAttributes;
         assert_eq!(
             movie_settings.displayed_attributes.unwrap(),
-            ["title", "description", "release_date", "genres"]
+            DisplayedAttributes::Fields(vec![
+                "title".to_string(),
+                "description".to_string(),
+                "release_date".to_string(),
+                "genres".to_string(),
+            ])
+        );
+        assert_eq!(
+            video_settings.displayed_attributes.unwrap(),
+            DisplayedAttributes::Fields(vec![])
         );
-        assert!(video_settings.displayed_attributes.unwrap().is_empty());
 
         use crate::settings::FilterableAttribute;
         assert_eq!(
@@ -782,6 +953,7 @@ Hint: It might not be working because you're not up to date with the Meilisearch
 
         Ok(())
     }
+    #[allow(unused)]
     #[derive(Serialize, Deserialize, IndexConfig)]
     struct Movie {
         #[index_config(primary_key)]
@@ -21,6 +21,13 @@ pub enum Error {
     /// A timeout happened while waiting for an update to complete.
     #[error("A task did not succeed in time.")]
     Timeout,
+
+    /// A request didn't get an answer within the timeout set with [`Client::with_request_timeout`](crate::client::Client::with_request_timeout).
+    #[error("The request to {url} timed out after {elapsed:?}.")]
+    RequestTimeout {
+        url: String,
+        elapsed: std::time::Duration,
+    },
     /// This Meilisearch SDK generated an invalid request (which was not sent).
     ///
     /// It probably comes from an invalid API key resulting in an invalid HTTP header.
@@ -62,6 +69,22 @@ pub enum Error {
     #[error("The uid provided to the token is not of version uuidv4")]
     InvalidUuid4Version,
 
+    /// The index uid contains characters other than alphanumerics, hyphens and underscores, or is longer than 512 bytes.
+    #[error("The index uid `{0}` is invalid. It must only contain alphanumeric characters, hyphens and underscores, and be at most 512 bytes long.")]
+    InvalidIndexUid(String),
+
+    /// The primary key contains characters other than alphanumerics and underscores.
+    #[error("The primary key `{0}` is invalid. It must only contain alphanumeric characters and underscores.")]
+    InvalidPrimaryKey(String),
+
+    /// A search parameter was rejected before sending the query.
+    #[error("Invalid search parameter: {0}")]
+    InvalidSearchParameter(String),
+
+    /// An argument given to the SDK was rejected before sending any request.
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
     #[error(transparent)]
     Other(Box<dyn std::error::Error + Send + Sync + 'static>),
 }
@@ -163,6 +186,7 @@ pub enum ErrorCode {
     InvalidApiKey,
     MissingAuthorizationHeader,
     TaskNotFound,
+    BatchNotFound,
     DumpNotFound,
     MissingMasterKey,
     NoSpaceLeftOnDevice,
@@ -355,6 +379,21 @@ mod test {
         let error = Error::Timeout;
         assert_eq!(error.to_string(), "A task did not succeed in time.");
 
+        let error = Error::RequestTimeout {
+            url: "http://localhost:7700/health".to_string(),
+            elapsed: std::time::Duration::from_millis(50),
+        };
+        assert_eq!(
+            error.to_string(),
+            "The request to http://localhost:7700/health timed out after 50ms."
+        );
+
+        let error = Error::InvalidArgument("an index can't be swapped with itself".to_string());
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument: an index can't be swapped with itself"
+        );
+
         let error = Error::InvalidRequest;
         assert_eq!(
             error.to_string(),
@@ -376,6 +415,18 @@ mod test {
             "The uid provided to the token is not of version uuidv4"
         );
 
+        let error = Error::InvalidIndexUid("my movies".to_string());
+        assert_eq!(
+            error.to_string(),
+            "The index uid `my movies` is invalid. It must only contain alphanumeric characters, hyphens and underscores, and be at most 512 bytes long."
+        );
+
+        let error = Error::InvalidPrimaryKey("movie-id".to_string());
+        assert_eq!(
+            error.to_string(),
+            "The primary key `movie-id` is invalid. It must only contain alphanumeric characters and underscores."
+        );
+
         let error = Error::Uuid(Uuid::parse_str("67e55044").unwrap_err());
         assert_eq!(error.to_string(), "The uid of the token has bit an uuid4 format: invalid length: expected length 32 for simple format, found 8");
 
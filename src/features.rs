@@ -176,4 +176,32 @@ mod tests {
         assert!(res.edit_documents_by_function);
         assert!(res.multimodal);
     }
+
+    #[tokio::test]
+    async fn test_update_contains_filter_request_body() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        let mock = s
+            .mock("PATCH", "/experimental-features")
+            .match_body(mockito::Matcher::Json(
+                serde_json::json!({ "containsFilter": true }),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"metrics":false,"logsRoute":false,"containsFilter":true,"network":false,"editDocumentsByFunction":false}"#,
+            )
+            .create_async()
+            .await;
+
+        let mut features = ExperimentalFeatures::new(&client);
+        features.set_contains_filter(true);
+        let res = features.update().await?;
+
+        mock.assert_async().await;
+        assert!(res.contains_filter);
+        assert!(!res.multimodal);
+        Ok(())
+    }
 }
@@ -1,12 +1,16 @@
 use crate::{
+    batches::BatchesQuery,
     client::Client,
-    documents::{DocumentDeletionQuery, DocumentQuery, DocumentsQuery, DocumentsResults},
+    documents::{
+        DocumentDeletionQuery, DocumentId, DocumentQuery, DocumentsQuery, DocumentsResults,
+    },
     errors::{Error, MeilisearchCommunicationError, MeilisearchError, MEILISEARCH_VERSION_HINT},
     request::*,
     search::*,
     similar::*,
     task_info::TaskInfo,
     tasks::*,
+    utils::ProgressBody,
     DefaultHttpClient,
 };
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
@@ -195,6 +199,7 @@ impl<Http: HttpClient> Index<Http> {
 
     /// Search for documents matching a specific query in the index.
     ///
+    /// The query is [validated](SearchQuery::validate) first.
     /// See also [`Index::search`].
     ///
     /// # Example
@@ -229,6 +234,8 @@ impl<Http: HttpClient> Index<Http> {
         &self,
         body: &SearchQuery<'_, Http>,
     ) -> Result<SearchResults<T>, Error> {
+        body.validate()?;
+
         self.client
             .http_client
             .request::<(), &SearchQuery<Http>, SearchResults<T>>(
@@ -280,6 +287,44 @@ impl<Http: HttpClient> Index<Http> {
         SearchQuery::new(self)
     }
 
+    /// Search for documents with a raw JSON body, sent verbatim to Meilisearch.
+    ///
+    /// This is an escape hatch for search parameters not yet modeled by [`SearchQuery`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # use serde_json::json;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let movies = client.index("search_raw");
+    /// # movies.add_or_replace(&[json!({"id": 1, "name": "Interstellar"})], Some("id")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// let results = movies
+    ///     .search_raw(json!({ "q": "Interstellar", "limit": 5 }))
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert_eq!(results["hits"][0]["name"], "Interstellar");
+    /// # movies.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn search_raw(&self, body: serde_json::Value) -> Result<serde_json::Value, Error> {
+        self.client
+            .http_client
+            .request::<(), serde_json::Value, serde_json::Value>(
+                &format!("{}/indexes/{}/search", self.client.host, self.uid),
+                Method::Post { body, query: () },
+                200,
+            )
+            .await
+    }
+
     /// Returns the facet stats matching a specific query in the index.
     ///
     /// See also [`Index::facet_search`].
@@ -367,11 +412,13 @@ impl<Http: HttpClient> Index<Http> {
     /// ```
     pub async fn get_document<T: 'static + DeserializeOwned + Send + Sync>(
         &self,
-        document_id: &str,
+        document_id: impl Into<DocumentId>,
     ) -> Result<T, Error> {
         let url = format!(
             "{}/indexes/{}/documents/{}",
-            self.client.host, self.uid, document_id
+            self.client.host,
+            self.uid,
+            document_id.into()
         );
         self.client
             .http_client
@@ -418,12 +465,14 @@ impl<Http: HttpClient> Index<Http> {
     /// # });
     pub async fn get_document_with<T: 'static + DeserializeOwned + Send + Sync>(
         &self,
-        document_id: &str,
+        document_id: impl Into<DocumentId>,
         document_query: &DocumentQuery<'_, Http>,
     ) -> Result<T, Error> {
         let url = format!(
             "{}/indexes/{}/documents/{}",
-            self.client.host, self.uid, document_id
+            self.client.host,
+            self.uid,
+            document_id.into()
         );
         self.client
             .http_client
@@ -476,6 +525,61 @@ impl<Http: HttpClient> Index<Http> {
             .await
     }
 
+    /// Get documents by batch without deserializing them into a concrete type.
+    ///
+    /// Useful when the shape of the documents is not known in advance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let movie_index = client.index("get_documents_raw");
+    /// # movie_index.add_or_replace(&[serde_json::json!({"id": 1, "name": "Interstellar"})], Some("id")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// let movies = movie_index.get_documents_raw().await.unwrap();
+    ///
+    /// assert_eq!(movies.results[0]["name"], "Interstellar");
+    /// # movie_index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_documents_raw(&self) -> Result<DocumentsResults<serde_json::Value>, Error> {
+        self.get_documents::<serde_json::Value>().await
+    }
+
+    /// Get one document without deserializing it into a concrete type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let movie_index = client.index("get_document_raw");
+    /// # movie_index.add_or_replace(&[serde_json::json!({"id": 1, "name": "Interstellar"})], Some("id")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// let movie = movie_index.get_document_raw(1).await.unwrap();
+    ///
+    /// assert_eq!(movie["name"], "Interstellar");
+    /// # movie_index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_document_raw(
+        &self,
+        document_id: impl Into<DocumentId>,
+    ) -> Result<serde_json::Value, Error> {
+        self.get_document::<serde_json::Value>(document_id).await
+    }
+
     /// Get documents by batch with parameters.
     ///
     /// # Example
@@ -623,6 +727,7 @@ impl<Http: HttpClient> Index<Http> {
         documents: &[T],
         primary_key: Option<&str>,
     ) -> Result<TaskInfo, Error> {
+        validate_primary_key(primary_key)?;
         let url = if let Some(primary_key) = primary_key {
             format!(
                 "{}/indexes/{}/documents?primaryKey={}",
@@ -644,6 +749,62 @@ impl<Http: HttpClient> Index<Http> {
             .await
     }
 
+    /// Add a list of documents or replace them if they already exist, reporting the upload progress.
+    ///
+    /// Behaves like [`Index::add_or_replace`], `on_progress` being called with the number of bytes sent so far
+    /// and the total size of the payload each time a chunk of the payload is sent.
+    /// When the request is retried, the chunks sent again aren't reported twice.
+    ///
+    /// **Note: in wasm the payload is read in memory before being sent, the progress then reaches the total before the request starts.**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///     name: String,
+    /// }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let movie_index = client.index("add_documents_with_progress");
+    ///
+    /// let task = movie_index
+    ///     .add_documents_with_progress(
+    ///         &[Movie { name: String::from("Interstellar") }],
+    ///         Some("name"),
+    ///         |sent, total| println!("{sent}/{total} bytes sent"),
+    ///     )
+    ///     .await
+    ///     .unwrap();
+    /// client.wait_for_task(task, None, None).await.unwrap();
+    /// # movie_index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn add_documents_with_progress<T, F>(
+        &self,
+        documents: &[T],
+        primary_key: Option<&str>,
+        on_progress: F,
+    ) -> Result<TaskInfo, Error>
+    where
+        T: Serialize + Send + Sync,
+        F: Fn(usize, usize) + Send + Sync + Unpin + 'static,
+    {
+        let payload = serde_json::to_vec(documents)?;
+        let total = payload.len();
+        let body = ProgressBody::new(futures_util::io::Cursor::new(payload), total, on_progress);
+
+        self.add_or_replace_unchecked_payload(body, "application/json", primary_key)
+            .await
+    }
+
     /// Add a raw and unchecked payload to meilisearch.
     ///
     /// This can be useful if your application is only forwarding data from other sources.
@@ -689,6 +850,7 @@ impl<Http: HttpClient> Index<Http> {
         content_type: &str,
         primary_key: Option<&str>,
     ) -> Result<TaskInfo, Error> {
+        validate_primary_key(primary_key)?;
         let url = if let Some(primary_key) = primary_key {
             format!(
                 "{}/indexes/{}/documents?primaryKey={}",
@@ -720,6 +882,28 @@ impl<Http: HttpClient> Index<Http> {
         self.add_or_replace(documents, primary_key).await
     }
 
+    /// Alias for [`Index::add_or_replace`].
+    ///
+    /// Documents that already exist are **entirely replaced** by the new ones. To only update some of their fields, use [`Index::add_or_update`].
+    pub async fn replace_documents<T: Serialize + Send + Sync>(
+        &self,
+        documents: &[T],
+        primary_key: Option<&str>,
+    ) -> Result<TaskInfo, Error> {
+        self.add_or_replace(documents, primary_key).await
+    }
+
+    /// Alias for [`Index::add_or_update`].
+    ///
+    /// Only the fields present in the new documents are updated, the other fields of the existing documents are kept. To entirely replace them, use [`Index::replace_documents`].
+    pub async fn update_documents<T: Serialize + Send + Sync>(
+        &self,
+        documents: &[T],
+        primary_key: Option<&str>,
+    ) -> Result<TaskInfo, Error> {
+        self.add_or_update(documents, primary_key).await
+    }
+
     /// Add a raw ndjson payload and update them if they already exist.
     ///
     /// It configures the correct content type for ndjson data.
@@ -959,6 +1143,7 @@ impl<Http: HttpClient> Index<Http> {
         documents: &[T],
         primary_key: Option<&str>,
     ) -> Result<TaskInfo, Error> {
+        validate_primary_key(primary_key)?;
         let url = if let Some(primary_key) = primary_key {
             format!(
                 "{}/indexes/{}/documents?primaryKey={}",
@@ -1027,6 +1212,7 @@ impl<Http: HttpClient> Index<Http> {
         content_type: &str,
         primary_key: Option<&str>,
     ) -> Result<TaskInfo, Error> {
+        validate_primary_key(primary_key)?;
         let url = if let Some(primary_key) = primary_key {
             format!(
                 "{}/indexes/{}/documents?primaryKey={}",
@@ -1129,13 +1315,15 @@ impl<Http: HttpClient> Index<Http> {
     /// # movies.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn delete_document<T: Display>(&self, uid: T) -> Result<TaskInfo, Error> {
+    pub async fn delete_document(&self, uid: impl Into<DocumentId>) -> Result<TaskInfo, Error> {
         self.client
             .http_client
             .request::<(), (), TaskInfo>(
                 &format!(
                     "{}/indexes/{}/documents/{}",
-                    self.client.host, self.uid, uid
+                    self.client.host,
+                    self.uid,
+                    uid.into()
                 ),
                 Method::Delete { query: () },
                 202,
@@ -1145,6 +1333,8 @@ impl<Http: HttpClient> Index<Http> {
 
     /// Delete a selection of documents based on array of document id's.
     ///
+    /// Returns [`Error::InvalidArgument`] without sending anything if no id is given.
+    ///
     /// # Example
     ///
     /// ```
@@ -1179,13 +1369,20 @@ impl<Http: HttpClient> Index<Http> {
     /// # movies.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn delete_documents<T: Display + Serialize + std::fmt::Debug + Send + Sync>(
+    pub async fn delete_documents(
         &self,
-        uids: &[T],
+        uids: impl IntoIterator<Item = impl Into<DocumentId>>,
     ) -> Result<TaskInfo, Error> {
+        let uids: Vec<DocumentId> = uids.into_iter().map(Into::into).collect();
+        if uids.is_empty() {
+            return Err(Error::InvalidArgument(
+                "at least one document id must be given".to_string(),
+            ));
+        }
+
         self.client
             .http_client
-            .request::<(), &[T], TaskInfo>(
+            .request::<(), Vec<DocumentId>, TaskInfo>(
                 &format!(
                     "{}/indexes/{}/documents/delete-batch",
                     self.client.host, self.uid
@@ -1418,11 +1615,11 @@ impl<Http: HttpClient> Index<Http> {
     /// # movies.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn get_task(&self, uid: impl AsRef<u32>) -> Result<Task, Error> {
+    pub async fn get_task(&self, uid: impl Into<TaskUid>) -> Result<Task, Error> {
         self.client
             .http_client
             .request::<(), (), Task>(
-                &format!("{}/tasks/{}", self.client.host, uid.as_ref()),
+                &format!("{}/tasks/{}", self.client.host, uid.into()),
                 Method::Get { query: () },
                 200,
             )
@@ -1489,6 +1686,63 @@ impl<Http: HttpClient> Index<Http> {
         self.client.get_tasks_with(&query).await
     }
 
+    /// Create a [`TasksSearchQuery`] already filtered on this [Index].
+    ///
+    /// The query can be refined with the other filters before being executed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # let index = client.create_index("tasks_query", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap().try_make_index(&client).unwrap();
+    /// let mut query = index.tasks_query();
+    /// query.with_statuses(["succeeded"]);
+    ///
+    /// let tasks = query.execute().await.unwrap();
+    ///
+    /// assert!(tasks.results.len() > 0);
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn tasks_query(&self) -> TasksSearchQuery<'_, Http> {
+        let mut query = TasksSearchQuery::new(&self.client);
+        query.with_index_uids([self.uid.as_str()]);
+        query
+    }
+
+    /// Create a [`BatchesQuery`] already filtered on this [Index].
+    ///
+    /// The query can be refined with the other filters before being executed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # let index = client.create_index("batches_query", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap().try_make_index(&client).unwrap();
+    /// let batches = index.batches_query().with_limit_owned(10).execute().await.unwrap();
+    ///
+    /// assert!(batches.results.len() > 0);
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn batches_query(&self) -> BatchesQuery<'_, Http> {
+        BatchesQuery::new(&self.client).with_index_uids_owned([self.uid.as_str()])
+    }
+
     /// Get stats of an index.
     ///
     /// # Example
@@ -1563,7 +1817,7 @@ impl<Http: HttpClient> Index<Http> {
     /// ```
     pub async fn wait_for_task(
         &self,
-        task_id: impl AsRef<u32>,
+        task_id: impl Into<TaskUid>,
         interval: Option<Duration>,
         timeout: Option<Duration>,
     ) -> Result<Task, Error> {
@@ -1780,6 +2034,228 @@ impl<Http: HttpClient> AsRef<str> for Index<Http> {
     }
 }
 
+impl<Http: HttpClient> Display for Index<Http> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.uid)
+    }
+}
+
+/// The uid of an [`Index`].
+///
+/// Parsing an [`IndexUid`] with [`FromStr`](std::str::FromStr) checks that it only contains
+/// alphanumeric characters, hyphens (`-`) and underscores (`_`), and that it is at most 512 bytes long.
+/// The `From` conversions don't do any check and let Meilisearch reject an invalid uid.
+/// Deserializing an [`IndexUid`] doesn't do any check either, since the uids returned by Meilisearch
+/// are the source of truth.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::indexes::IndexUid;
+/// let uid: IndexUid = "movies".parse().unwrap();
+/// assert_eq!(uid.as_ref(), "movies");
+///
+/// assert!("my movies".parse::<IndexUid>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct IndexUid(String);
+
+impl IndexUid {
+    /// Maximum length of an index uid, in bytes.
+    pub const MAX_LENGTH: usize = 512;
+}
+
+impl std::str::FromStr for IndexUid {
+    type Err = Error;
+
+    fn from_str(uid: &str) -> Result<Self, Self::Err> {
+        let is_valid = !uid.is_empty()
+            && uid.len() <= Self::MAX_LENGTH
+            && uid
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_');
+
+        if is_valid {
+            Ok(IndexUid(uid.to_string()))
+        } else {
+            Err(Error::InvalidIndexUid(uid.to_string()))
+        }
+    }
+}
+
+impl Display for IndexUid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for IndexUid {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::borrow::Borrow<str> for IndexUid {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for IndexUid {
+    fn from(uid: String) -> Self {
+        IndexUid(uid)
+    }
+}
+
+impl From<&String> for IndexUid {
+    fn from(uid: &String) -> Self {
+        IndexUid(uid.clone())
+    }
+}
+
+impl From<&str> for IndexUid {
+    fn from(uid: &str) -> Self {
+        IndexUid(uid.to_string())
+    }
+}
+
+impl From<IndexUid> for String {
+    fn from(uid: IndexUid) -> Self {
+        uid.0
+    }
+}
+
+/// Checks that a primary key name only contains alphanumeric characters and underscores.
+fn validate_primary_key(primary_key: Option<&str>) -> Result<(), Error> {
+    match primary_key {
+        Some(primary_key)
+            if primary_key.is_empty()
+                || !primary_key
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'_') =>
+        {
+            Err(Error::InvalidPrimaryKey(primary_key.to_string()))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// An [`Index`] whose documents are all of type `T`.
+///
+/// Its methods are pre-typed, so there is no need to specify the document type at each call site.
+///
+/// # Example
+///
+/// ```
+/// # use serde::{Serialize, Deserialize};
+/// # use meilisearch_sdk::{client::*, indexes::*, search::*};
+/// #
+/// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+/// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+/// #
+/// #[derive(Serialize, Deserialize, Debug)]
+/// struct Movie {
+///     name: String,
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+/// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+/// let movies = TypedIndex::<Movie, _>::new(client.index("typed_index"));
+/// # movies.add_or_replace(&[Movie { name: String::from("Interstellar") }], Some("name")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+///
+/// let query = SearchQuery::new(movies.index()).with_query("Interstellar").build();
+/// let results = movies.search(&query).await.unwrap();
+///
+/// assert_eq!(results.hits[0].result.name, "Interstellar");
+/// # movies.into_inner().delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+/// # });
+/// ```
+pub struct TypedIndex<T, Http: HttpClient = DefaultHttpClient> {
+    index: Index<Http>,
+    documents: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T, Http: HttpClient> TypedIndex<T, Http> {
+    #[must_use]
+    pub fn new(index: Index<Http>) -> TypedIndex<T, Http> {
+        TypedIndex {
+            index,
+            documents: std::marker::PhantomData,
+        }
+    }
+
+    /// The untyped [`Index`].
+    #[must_use]
+    pub fn index(&self) -> &Index<Http> {
+        &self.index
+    }
+
+    /// Returns the untyped [`Index`].
+    #[must_use]
+    pub fn into_inner(self) -> Index<Http> {
+        self.index
+    }
+}
+
+impl<T: 'static + DeserializeOwned + Send + Sync, Http: HttpClient> TypedIndex<T, Http> {
+    /// See [`Index::execute_query`].
+    pub async fn search(&self, query: &SearchQuery<'_, Http>) -> Result<SearchResults<T>, Error> {
+        self.index.execute_query::<T>(query).await
+    }
+
+    /// See [`Index::get_document`].
+    pub async fn get_document(&self, document_id: impl Into<DocumentId>) -> Result<T, Error> {
+        self.index.get_document::<T>(document_id).await
+    }
+
+    /// See [`Index::get_documents`].
+    pub async fn get_documents(&self) -> Result<DocumentsResults<T>, Error> {
+        self.index.get_documents::<T>().await
+    }
+}
+
+impl<T: Serialize + Send + Sync, Http: HttpClient> TypedIndex<T, Http> {
+    /// See [`Index::add_or_replace`].
+    pub async fn add_or_replace(
+        &self,
+        documents: &[T],
+        primary_key: Option<&str>,
+    ) -> Result<TaskInfo, Error> {
+        self.index.add_or_replace(documents, primary_key).await
+    }
+
+    /// See [`Index::add_or_update`].
+    pub async fn add_or_update(
+        &self,
+        documents: &[T],
+        primary_key: Option<&str>,
+    ) -> Result<TaskInfo, Error> {
+        self.index.add_or_update(documents, primary_key).await
+    }
+}
+
+impl<T, Http: HttpClient> Clone for TypedIndex<T, Http> {
+    fn clone(&self) -> Self {
+        TypedIndex::new(self.index.clone())
+    }
+}
+
+impl<T, Http: HttpClient + std::fmt::Debug> std::fmt::Debug for TypedIndex<T, Http> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypedIndex")
+            .field("index", &self.index)
+            .field("documents", &std::any::type_name::<T>())
+            .finish()
+    }
+}
+
+impl<T, Http: HttpClient> From<Index<Http>> for TypedIndex<T, Http> {
+    fn from(index: Index<Http>) -> Self {
+        TypedIndex::new(index)
+    }
+}
+
 /// An [`IndexUpdater`] used to update the specifics of an index.
 ///
 /// # Example
@@ -1961,7 +2437,7 @@ impl<'a, Http: HttpClient> AsRef<IndexUpdater<'a, Http>> for IndexUpdater<'a, Ht
 #[serde(rename_all = "camelCase")]
 pub struct IndexStats {
     /// Total number of documents in an index
-    pub number_of_documents: usize,
+    pub number_of_documents: u64,
 
     /// Total number of documents with at least one embedding
     pub number_of_embedded_documents: usize,
@@ -1970,7 +2446,10 @@ pub struct IndexStats {
     pub number_of_embeddings: usize,
 
     /// Storage space claimed by all documents in the index in bytes
-    pub raw_document_db_size: usize,
+    ///
+    /// `None` on Meilisearch versions that don't report it.
+    #[serde(default)]
+    pub raw_document_db_size: Option<u64>,
 
     /// Total size of the documents stored in an index divided by the number of documents in that same index
     pub avg_document_size: usize,
@@ -2168,6 +2647,113 @@ mod tests {
     use meilisearch_test_macro::meilisearch_test;
     use serde_json::json;
 
+    #[tokio::test]
+    async fn test_batches_query_is_filtered_on_the_index() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let index = client.index("movies");
+
+        let mock = s
+            .mock("GET", "/batches?limit=5&indexUids=movies")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"results":[],"total":0,"limit":5,"from":null,"next":null}"#)
+            .create_async()
+            .await;
+
+        index.batches_query().with_limit_owned(5).execute().await?;
+
+        mock.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tasks_query_is_filtered_on_the_index() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let index = client.index("movies");
+
+        let mock = s
+            .mock("GET", "/tasks?indexUids=movies&statuses=succeeded")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"results":[],"total":0,"limit":20,"from":null,"next":null}"#)
+            .create_async()
+            .await;
+
+        let mut query = index.tasks_query();
+        query.with_statuses(["succeeded"]);
+        query.execute().await?;
+
+        mock.assert_async().await;
+        Ok(())
+    }
+
+    #[test]
+    fn test_display_index() {
+        let client = Client::new("http://localhost:7700", None::<String>).unwrap();
+        let index = client.index("movies");
+
+        assert_eq!(format!("{}", index), "movies");
+        assert_eq!(index.as_ref(), "movies");
+    }
+
+    #[test]
+    fn test_parse_valid_index_uid() {
+        for uid in [
+            "movies",
+            "movies_2024",
+            "my-movies",
+            "MOVIES",
+            "0",
+            &"a".repeat(512),
+        ] {
+            let parsed: IndexUid = uid.parse().unwrap();
+            assert_eq!(parsed.as_ref(), uid);
+            assert_eq!(parsed.to_string(), uid);
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid_index_uid() {
+        for uid in [
+            "",
+            "my movies",
+            "movies!",
+            "movies/2024",
+            "h\u{e9}llo",
+            &"a".repeat(513),
+        ] {
+            assert!(matches!(
+                uid.parse::<IndexUid>(),
+                Err(Error::InvalidIndexUid(invalid)) if invalid == uid
+            ));
+        }
+    }
+
+    #[test]
+    fn test_index_uid_serializes_as_string() {
+        let uid: IndexUid = "movies".parse().unwrap();
+        assert_eq!(serde_json::to_value(&uid).unwrap(), json!("movies"));
+        assert_eq!(
+            serde_json::from_value::<IndexUid>(json!("movies")).unwrap(),
+            uid
+        );
+        assert_eq!(
+            serde_json::from_value::<IndexUid>(json!("my movies")).unwrap(),
+            IndexUid::from("my movies")
+        );
+        assert_eq!(String::from(uid), "movies");
+    }
+
+    fn assert_index_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_index_is_send_and_sync() {
+        assert_index_send_sync::<Index<crate::reqwest::ReqwestClient>>();
+        assert_index_send_sync::<Index>();
+    }
+
     #[meilisearch_test]
     async fn test_from_value(client: Client) {
         let t = OffsetDateTime::now_utc();
@@ -2209,6 +2795,64 @@ mod tests {
         assert!(index.primary_key.is_none());
     }
 
+    #[tokio::test]
+    async fn test_index_timestamps_come_from_get_index() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        let _m = s
+            .mock("GET", "/indexes/movies")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"uid":"movies","primaryKey":"id","createdAt":"2024-10-11T11:49:53.000Z","updatedAt":"2024-10-12T08:00:00Z"}"#)
+            .create_async()
+            .await;
+
+        let index = client.index("movies");
+        assert_eq!(index.created_at, None);
+        assert_eq!(index.updated_at, None);
+
+        let index = client.get_index("movies").await?;
+        let parse = |date| {
+            OffsetDateTime::parse(date, &time::format_description::well_known::Rfc3339).unwrap()
+        };
+        assert_eq!(index.created_at, Some(parse("2024-10-11T11:49:53.000Z")));
+        assert_eq!(index.updated_at, Some(parse("2024-10-12T08:00:00Z")));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_primary_key_is_refreshed_by_fetch_info() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        // freshly created index, without any document
+        let empty = s
+            .mock("GET", "/indexes/movies")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"uid":"movies","primaryKey":null,"createdAt":"2024-10-11T11:49:53.000Z","updatedAt":"2024-10-11T11:49:53.000Z"}"#)
+            .create_async()
+            .await;
+
+        let mut index = client.get_index("movies").await?;
+        assert_eq!(index.primary_key, None);
+        empty.remove_async().await;
+
+        // the primary key was inferred when the first documents were added
+        let _filled = s
+            .mock("GET", "/indexes/movies")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"uid":"movies","primaryKey":"id","createdAt":"2024-10-11T11:49:53.000Z","updatedAt":"2024-10-11T11:50:00.000Z"}"#)
+            .create_async()
+            .await;
+
+        index.fetch_info().await?;
+        assert_eq!(index.primary_key.as_deref(), Some("id"));
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_get_documents(index: Index) {
         #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -2502,4 +3146,327 @@ mod tests {
         assert!(task.is_success());
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_get_documents_raw() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let index = client.index("movies");
+
+        let documents_mock = s
+            .mock("GET", "/indexes/movies/documents")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"results":[{"id":1,"title":"Interstellar"}],"offset":0,"limit":20,"total":1}"#,
+            )
+            .create_async()
+            .await;
+        let document_mock = s
+            .mock("GET", "/indexes/movies/documents/1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"id":1,"title":"Interstellar"}"#)
+            .create_async()
+            .await;
+
+        let documents = index.get_documents_raw().await?;
+        assert_eq!(documents.total, 1);
+        assert_eq!(
+            documents.results,
+            vec![json!({"id": 1, "title": "Interstellar"})]
+        );
+
+        let document = index.get_document_raw(1).await?;
+        assert_eq!(document, json!({"id": 1, "title": "Interstellar"}));
+
+        documents_mock.assert_async().await;
+        document_mock.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_search_raw_sends_body_verbatim() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let index = client.index("movies");
+
+        let body = json!({ "q": "Interstellar", "someExperimentalParameter": { "enabled": true } });
+        let mock = s
+            .mock("POST", "/indexes/movies/search")
+            .match_body(mockito::Matcher::Json(body.clone()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"hits":[{"id":1}],"query":"Interstellar","processingTimeMs":1,"experimental":"value"}"#)
+            .create_async()
+            .await;
+
+        let results = index.search_raw(body).await?;
+
+        assert_eq!(
+            results,
+            json!({
+                "hits": [{ "id": 1 }],
+                "query": "Interstellar",
+                "processingTimeMs": 1,
+                "experimental": "value"
+            })
+        );
+        mock.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_add_documents_with_progress() -> Result<(), Error> {
+        use std::sync::{Arc, Mutex};
+
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let index = client.index("movies");
+
+        let documents = vec![json!({"id": 1, "title": "Interstellar"}), json!({"id": 2})];
+        let total = serde_json::to_vec(&documents).unwrap().len();
+        let mock = s
+            .mock("POST", "/indexes/movies/documents?primaryKey=id")
+            .match_header("content-type", "application/json")
+            .match_body(mockito::Matcher::Json(json!(documents)))
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"taskUid":1,"indexUid":"movies","status":"enqueued","type":"documentAdditionOrUpdate","enqueuedAt":"2024-10-11T11:49:53.000Z"}"#)
+            .create_async()
+            .await;
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorder = Arc::clone(&events);
+        index
+            .add_documents_with_progress(&documents, Some("id"), move |sent, total| {
+                recorder.lock().unwrap().push((sent, total));
+            })
+            .await?;
+
+        mock.assert_async().await;
+
+        let events = events.lock().unwrap();
+        assert!(!events.is_empty());
+        assert!(events.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(events.last(), Some(&(total, total)));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_add_documents_with_progress_and_retry_policy() -> Result<(), Error> {
+        use std::sync::{Arc, Mutex};
+
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>)
+            .unwrap()
+            .with_retry_policy(Box::new(crate::retry::ExponentialBackoffRetryPolicy::new(
+                2,
+                Duration::from_millis(1),
+                2.0,
+            )?));
+        let index = client.index("movies");
+
+        let documents = vec![json!({"id": 1, "title": "Interstellar"}), json!({"id": 2})];
+        let total = serde_json::to_vec(&documents).unwrap().len();
+        let mock = s
+            .mock("POST", "/indexes/movies/documents?primaryKey=id")
+            .match_body(mockito::Matcher::Json(json!(documents)))
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"taskUid":1,"indexUid":"movies","status":"enqueued","type":"documentAdditionOrUpdate","enqueuedAt":"2024-10-11T11:49:53.000Z"}"#)
+            .create_async()
+            .await;
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorder = Arc::clone(&events);
+        index
+            .add_documents_with_progress(&documents, Some("id"), move |sent, total| {
+                recorder.lock().unwrap().push((sent, total));
+            })
+            .await?;
+
+        mock.assert_async().await;
+
+        let events = events.lock().unwrap();
+        assert!(events.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(events.last(), Some(&(total, total)));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_replace_and_update_documents_http_methods() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let index = client.index("movies");
+        let task = r#"{"taskUid":1,"indexUid":"movies","status":"enqueued","type":"documentAdditionOrUpdate","enqueuedAt":"2024-10-11T11:49:53.000Z"}"#;
+
+        let post = s
+            .mock("POST", "/indexes/movies/documents")
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(task)
+            .expect(2)
+            .create_async()
+            .await;
+        let put = s
+            .mock("PUT", "/indexes/movies/documents")
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(task)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let documents = [json!({"id": 1, "title": "Interstellar"})];
+        index.replace_documents(&documents, None).await?;
+        index.add_documents(&documents, None).await?;
+        index.add_or_update(&documents, None).await?;
+        index.update_documents(&documents, None).await?;
+
+        post.assert_async().await;
+        put.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete_documents_from_iterators() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let index = client.index("movies");
+        let task = r#"{"taskUid":1,"indexUid":"movies","status":"enqueued","type":"documentDeletion","enqueuedAt":"2024-10-11T11:49:53.000Z"}"#;
+
+        let strings = s
+            .mock("POST", "/indexes/movies/documents/delete-batch")
+            .match_body(mockito::Matcher::Json(json!(["Interstellar", "Unknown"])))
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(task)
+            .expect(3)
+            .create_async()
+            .await;
+        let integers = s
+            .mock("POST", "/indexes/movies/documents/delete-batch")
+            .match_body(mockito::Matcher::Json(json!([1, 2, 3])))
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(task)
+            .expect(3)
+            .create_async()
+            .await;
+
+        index.delete_documents(["Interstellar", "Unknown"]).await?;
+        index.delete_documents(&["Interstellar", "Unknown"]).await?;
+        index
+            .delete_documents(&vec!["Interstellar".to_string(), "Unknown".to_string()])
+            .await?;
+        index.delete_documents(1..=3_i64).await?;
+        index.delete_documents(&[1, 2, 3]).await?;
+        index.delete_documents(&[1u64, 2, 3][..]).await?;
+
+        strings.assert_async().await;
+        integers.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete_documents_rejects_empty_list() {
+        let client = Client::new("http://localhost:7700", None::<String>).unwrap();
+        let index = client.index("movies");
+
+        let res = index.delete_documents(Vec::<String>::new()).await;
+        assert!(matches!(res, Err(Error::InvalidArgument(_))));
+    }
+
+    #[tokio::test]
+    async fn test_typed_index() -> Result<(), Error> {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Movie {
+            id: u32,
+            title: String,
+        }
+
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let movies = TypedIndex::<Movie, _>::new(client.index("movies"));
+        let interstellar = Movie {
+            id: 1,
+            title: S("Interstellar"),
+        };
+
+        let add_mock = s
+            .mock("POST", "/indexes/movies/documents")
+            .match_body(mockito::Matcher::Json(json!([{"id": 1, "title": "Interstellar"}])))
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"taskUid":1,"indexUid":"movies","status":"enqueued","type":"documentAdditionOrUpdate","enqueuedAt":"2024-10-11T11:49:53.000Z"}"#)
+            .create_async()
+            .await;
+        let search_mock = s
+            .mock("POST", "/indexes/movies/search")
+            .match_body(mockito::Matcher::Json(json!({"q": "space"})))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"hits":[{"id":1,"title":"Interstellar"}],"query":"space","processingTimeMs":1,"limit":20,"offset":0,"estimatedTotalHits":1}"#)
+            .create_async()
+            .await;
+        let document_mock = s
+            .mock("GET", "/indexes/movies/documents/1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"id":1,"title":"Interstellar"}"#)
+            .create_async()
+            .await;
+
+        movies.add_or_replace(&[interstellar], None).await?;
+
+        let query = SearchQuery::new(movies.index()).with_query("space").build();
+        let results = movies.search(&query).await?;
+        assert_eq!(results.hits[0].result.title, "Interstellar");
+
+        let movie = movies.get_document(1).await?;
+        assert_eq!(movie.id, 1);
+
+        add_mock.assert_async().await;
+        search_mock.assert_async().await;
+        document_mock.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_add_documents_validates_primary_key() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let index = client.index("movies");
+        let documents = [json!({"id": 1})];
+
+        let mock = s
+            .mock("POST", "/indexes/movies/documents")
+            .match_query(mockito::Matcher::Any)
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"taskUid":1,"indexUid":"movies","status":"enqueued","type":"documentAdditionOrUpdate","enqueuedAt":"2024-10-11T11:49:53.000Z"}"#)
+            .expect(3)
+            .create_async()
+            .await;
+
+        for primary_key in ["id", "movie_id", "Id2"] {
+            index.add_documents(&documents, Some(primary_key)).await?;
+        }
+
+        for primary_key in ["movie id", "movie-id", "movie.id", ""] {
+            let error = index
+                .add_documents(&documents, Some(primary_key))
+                .await
+                .unwrap_err();
+            assert!(
+                matches!(&error, Error::InvalidPrimaryKey(key) if key == primary_key),
+                "{:?}",
+                error
+            );
+        }
+
+        mock.assert_async().await;
+        Ok(())
+    }
 }
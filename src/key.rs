@@ -56,6 +56,33 @@ impl Key {
         self
     }
 
+    /// Value of the `Authorization` header to send to authenticate with this [Key].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::key::Key;
+    /// # let key: Key = serde_json::from_value(serde_json::json!({
+    /// #     "actions": ["search"],
+    /// #     "createdAt": "2024-10-11T11:49:53.000Z",
+    /// #     "updatedAt": "2024-10-11T11:49:53.000Z",
+    /// #     "description": null,
+    /// #     "name": null,
+    /// #     "expiresAt": null,
+    /// #     "indexes": ["*"],
+    /// #     "key": "d0552b41536279a0ad88bd595327b96f01176a60c2243e906c52ac02375f9bc4",
+    /// #     "uid": "6062abda-a5aa-4414-ac91-ecd7944c0f8d",
+    /// # })).unwrap();
+    /// assert_eq!(
+    ///     key.to_authorization_header(),
+    ///     "Bearer d0552b41536279a0ad88bd595327b96f01176a60c2243e906c52ac02375f9bc4"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_authorization_header(&self) -> String {
+        format!("Bearer {}", self.key)
+    }
+
     /// Update the name of the [Key].
     ///
     /// # Example
@@ -727,4 +754,93 @@ pub struct KeysResults {
     pub results: Vec<Key>,
     pub limit: u32,
     pub offset: u32,
+    pub total: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::format_description::well_known::Rfc3339;
+
+    #[test]
+    fn test_to_authorization_header() {
+        let key: Key = serde_json::from_value(serde_json::json!({
+            "actions": ["search"],
+            "createdAt": "2024-10-11T11:49:53.000Z",
+            "updatedAt": "2024-10-11T11:49:53.000Z",
+            "description": null,
+            "name": null,
+            "expiresAt": null,
+            "indexes": ["*"],
+            "key": "d0552b41536279a0ad88bd595327b96f01176a60c2243e906c52ac02375f9bc4",
+            "uid": "6062abda-a5aa-4414-ac91-ecd7944c0f8d",
+        }))
+        .unwrap();
+
+        assert_eq!(
+            key.to_authorization_header(),
+            "Bearer d0552b41536279a0ad88bd595327b96f01176a60c2243e906c52ac02375f9bc4"
+        );
+        assert_eq!(key.uid, "6062abda-a5aa-4414-ac91-ecd7944c0f8d");
+    }
+
+    #[test]
+    fn test_deserialize_key_timestamps() {
+        let key: Key = serde_json::from_str(
+            r#"{
+                "name": "Default Search API Key",
+                "description": "Use it to search from the frontend",
+                "key": "0a6e572506c52ab0bd6195921575d23092b7f0c284ab4ac86d12346c33057f99",
+                "uid": "74c9c733-3368-4738-bbe5-1d18a5fecb37",
+                "actions": ["search"],
+                "indexes": ["*"],
+                "expiresAt": "2025-01-01T00:00:00Z",
+                "createdAt": "2021-08-11T10:00:00.123456Z",
+                "updatedAt": "2021-08-12T10:00:00Z"
+            }"#,
+        )
+        .unwrap();
+
+        let parse = |date| OffsetDateTime::parse(date, &Rfc3339).unwrap();
+        assert_eq!(key.created_at, parse("2021-08-11T10:00:00.123456Z"));
+        assert_eq!(key.updated_at, parse("2021-08-12T10:00:00Z"));
+        assert_eq!(key.expires_at, Some(parse("2025-01-01T00:00:00Z")));
+
+        // timestamps are set by Meilisearch and never sent back
+        let value = serde_json::to_value(&key).unwrap();
+        assert!(value.get("createdAt").is_none());
+        assert!(value.get("updatedAt").is_none());
+    }
+
+    #[test]
+    fn test_unknown_action_round_trip() {
+        let key: Key = serde_json::from_str(
+            r#"{
+                "name": null,
+                "description": null,
+                "key": "0a6e572506c52ab0bd6195921575d23092b7f0c284ab4ac86d12346c33057f99",
+                "uid": "74c9c733-3368-4738-bbe5-1d18a5fecb37",
+                "actions": ["search", "tasks:someNewAction"],
+                "indexes": ["*"],
+                "expiresAt": null,
+                "createdAt": "2021-08-11T10:00:00Z",
+                "updatedAt": "2021-08-12T10:00:00Z"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            key.actions,
+            vec![
+                Action::Search,
+                Action::Unknown("tasks:someNewAction".to_string())
+            ]
+        );
+
+        let value = serde_json::to_value(&key).unwrap();
+        assert_eq!(
+            value["actions"],
+            serde_json::json!(["search", "tasks:someNewAction"])
+        );
+    }
 }
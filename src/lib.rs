@@ -224,6 +224,16 @@
 //! initializing the `Client` with the `new_with_client` method.
 //! You may be interested by the `futures-unsend` feature which lets you specify a non-Send http client.
 //!
+//! ### Metrics <!-- omit in TOC -->
+//!
+//! The `metrics` feature records `prometheus` counters and histograms for every request sent by the built-in http client.
+//! Call `metrics::register_metrics` with your own registry to expose them. No metrics are recorded in wasm.
+//!
+//! ### Logging <!-- omit in TOC -->
+//!
+//! The `log` feature logs every request sent by the built-in http client, and the status of its response, at the `DEBUG` level of the [`log`](https://docs.rs/log) crate.
+//! The SDK has no `tracing` feature, the two are layered: a `tracing` subscriber collects these records through [`tracing-log`](https://docs.rs/tracing-log).
+//!
 //! ### Wasm support <!-- omit in TOC -->
 //!
 //! The SDK supports wasm through reqwest. You'll need to enable the `futures-unsend` feature while importing it, though.
@@ -248,9 +258,14 @@ pub mod features;
 pub mod indexes;
 /// Module containing the [`Key`](key::Key) struct.
 pub mod key;
+/// Module exposing `prometheus` metrics about the requests sent by the SDK.
+#[cfg(feature = "metrics")]
+pub mod metrics;
 /// Module for Network configuration API (sharding/remotes).
 pub mod network;
 pub mod request;
+/// Module containing the [`RetryPolicy`](retry::RetryPolicy) applied to failing requests.
+pub mod retry;
 /// Module related to search queries and results.
 pub mod search;
 /// Module containing [`Settings`](settings::Settings).
@@ -263,9 +278,9 @@ pub mod snapshots;
 pub mod task_info;
 /// Module representing the [`Task`](tasks::Task)s.
 pub mod tasks;
-/// Module that generates tenant tokens.
+/// Module that generates [tenant tokens](client::Client::generate_tenant_token).
 #[cfg(not(target_arch = "wasm32"))]
-mod tenant_tokens;
+pub mod tenant_tokens;
 /// Module containing utilizes functions.
 mod utils;
 /// Module to manage webhooks.
@@ -0,0 +1,180 @@
+use std::{sync::OnceLock, time::Duration};
+
+use prometheus::{HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry};
+
+struct Metrics {
+    requests_total: IntCounterVec,
+    request_duration_seconds: HistogramVec,
+}
+
+fn metrics() -> &'static Metrics {
+    static METRICS: OnceLock<Metrics> = OnceLock::new();
+
+    METRICS.get_or_init(|| Metrics {
+        requests_total: IntCounterVec::new(
+            Opts::new(
+                "meilisearch_sdk_requests_total",
+                "Number of requests sent to Meilisearch.",
+            ),
+            &["method", "path", "status"],
+        )
+        .unwrap(),
+        request_duration_seconds: HistogramVec::new(
+            HistogramOpts::new(
+                "meilisearch_sdk_request_duration_seconds",
+                "Duration of the requests sent to Meilisearch, in seconds.",
+            ),
+            &["method", "path"],
+        )
+        .unwrap(),
+    })
+}
+
+/// Register the SDK metrics in the given [`Registry`].
+///
+/// The following metrics are exposed:
+/// - `meilisearch_sdk_requests_total{method, path, status}`, a counter of the requests sent.
+/// - `meilisearch_sdk_request_duration_seconds{method, path}`, a histogram of their duration.
+///
+/// The `path` label is the route rather than the requested path, like `/indexes/{index_uid}/documents/{document_id}`,
+/// so the number of series doesn't grow with the indexes and documents.
+/// Requests failing before Meilisearch answered are counted with the `error` status.
+///
+/// **Note: no metrics are recorded in wasm.**
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::metrics::register_metrics;
+/// let mut registry = prometheus::Registry::new();
+///
+/// register_metrics(&mut registry).unwrap();
+/// ```
+pub fn register_metrics(registry: &mut Registry) -> Result<(), prometheus::Error> {
+    let metrics = metrics();
+
+    registry.register(Box::new(metrics.requests_total.clone()))?;
+    registry.register(Box::new(metrics.request_duration_seconds.clone()))?;
+
+    Ok(())
+}
+
+#[cfg_attr(
+    any(not(feature = "reqwest"), target_arch = "wasm32"),
+    allow(dead_code)
+)]
+pub(crate) fn observe_request(method: &str, path: &str, status: Option<u16>, duration: Duration) {
+    let metrics = metrics();
+    let status = status.map_or_else(|| "error".to_string(), |status| status.to_string());
+    let route = route_template(path);
+
+    metrics
+        .requests_total
+        .with_label_values(&[method, &route, &status])
+        .inc();
+    metrics
+        .request_duration_seconds
+        .with_label_values(&[method, &route])
+        .observe(duration.as_secs_f64());
+}
+
+/// Replace the index uids, document ids, task uids… of a path with placeholders.
+fn route_template(path: &str) -> String {
+    let mut route = String::new();
+    let mut previous = "";
+
+    for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+        let placeholder = match previous {
+            "indexes" => Some("{index_uid}"),
+            "documents" if !matches!(segment, "delete" | "delete-batch" | "fetch") => {
+                Some("{document_id}")
+            }
+            "tasks" | "batches" if segment != "cancel" => Some("{uid}"),
+            "keys" => Some("{key}"),
+            "webhooks" => Some("{uuid}"),
+            "chats" => Some("{workspace_uid}"),
+            _ => None,
+        };
+
+        route.push('/');
+        route.push_str(placeholder.unwrap_or(segment));
+        previous = segment;
+    }
+
+    if route.is_empty() {
+        route.push('/');
+    }
+    route
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Client;
+
+    #[test]
+    fn test_route_template() {
+        for (path, route) in [
+            ("/version", "/version"),
+            ("/", "/"),
+            ("/indexes", "/indexes"),
+            ("/indexes/movies", "/indexes/{index_uid}"),
+            (
+                "/indexes/movies/documents/42",
+                "/indexes/{index_uid}/documents/{document_id}",
+            ),
+            (
+                "/indexes/movies/documents/delete-batch",
+                "/indexes/{index_uid}/documents/delete-batch",
+            ),
+            (
+                "/indexes/movies/settings/filterable-attributes",
+                "/indexes/{index_uid}/settings/filterable-attributes",
+            ),
+            ("/tasks/12", "/tasks/{uid}"),
+            ("/tasks/cancel", "/tasks/cancel"),
+            ("/batches/3", "/batches/{uid}"),
+            ("/keys/74c9c733", "/keys/{key}"),
+            ("/webhooks/627ea538", "/webhooks/{uuid}"),
+            (
+                "/chats/support/chat/completions",
+                "/chats/{workspace_uid}/chat/completions",
+            ),
+        ] {
+            assert_eq!(route_template(path), route);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_requests_are_counted() {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        // no other test answers with a 418, so the counter is only incremented here
+        s.mock("GET", "/version")
+            .with_status(418)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"commitSha":"b46889b5f0f2f8b91438a08a358ba8f05fc09fc1","commitDate":"2021-07-08","pkgVersion":"0.23.0"}"#)
+            .create_async()
+            .await;
+
+        let counter = metrics()
+            .requests_total
+            .with_label_values(&["GET", "/version", "418"]);
+        assert_eq!(counter.get(), 0);
+
+        let _ = client.get_version().await;
+
+        assert_eq!(counter.get(), 1);
+
+        let mut registry = Registry::new();
+        register_metrics(&mut registry).unwrap();
+        let families = registry.gather();
+        assert!(families
+            .iter()
+            .any(|family| family.name() == "meilisearch_sdk_requests_total"));
+        assert!(families
+            .iter()
+            .any(|family| family.name() == "meilisearch_sdk_request_duration_seconds"));
+    }
+}
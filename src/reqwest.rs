@@ -1,23 +1,43 @@
 use std::{
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
+    time::Duration,
 };
 
 use async_trait::async_trait;
 use bytes::{Bytes, BytesMut};
 use futures_core::Stream;
 use futures_io::AsyncRead;
+#[cfg(feature = "log")]
+use log::debug;
 use pin_project_lite::pin_project;
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
     errors::Error,
     request::{parse_response, HttpClient, Method},
+    retry::RetryPolicy,
+    utils::SleepBackend,
 };
 
-#[derive(Debug, Clone, Default)]
+#[derive(Clone, Default)]
 pub struct ReqwestClient {
     client: reqwest::Client,
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
+    retry_non_idempotent: bool,
+    request_timeout: Option<Duration>,
+}
+
+impl std::fmt::Debug for ReqwestClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReqwestClient")
+            .field("client", &self.client)
+            .field("retry_policy", &self.retry_policy.is_some())
+            .field("retry_non_idempotent", &self.retry_non_idempotent)
+            .field("request_timeout", &self.request_timeout)
+            .finish()
+    }
 }
 
 impl ReqwestClient {
@@ -47,7 +67,42 @@ impl ReqwestClient {
         let builder = builder.default_headers(headers);
         let client = builder.build()?;
 
-        Ok(ReqwestClient { client })
+        Ok(ReqwestClient {
+            client,
+            retry_policy: None,
+            retry_non_idempotent: false,
+            request_timeout: None,
+        })
+    }
+
+    /// Retry the requests failing according to the given [`RetryPolicy`].
+    ///
+    /// Only the `GET` and `DELETE` requests, and the requests that couldn't connect to Meilisearch, are given to the policy,
+    /// unless [`with_non_idempotent_retries`](Self::with_non_idempotent_retries) is set.
+    ///
+    /// Request bodies are kept in memory as they are sent, so they can be sent again.
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: Box<dyn RetryPolicy>) -> Self {
+        self.retry_policy = Some(Arc::from(retry_policy));
+        self
+    }
+
+    /// Also give the `POST`, `PUT` and `PATCH` requests that failed after connecting to Meilisearch to the [`RetryPolicy`].
+    ///
+    /// **These requests may have been processed by Meilisearch, retrying them can enqueue the same task twice.**
+    #[must_use]
+    pub fn with_non_idempotent_retries(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    /// Give up on the requests that didn't get an answer after `timeout`, returning [`Error::RequestTimeout`].
+    ///
+    /// **Timeouts aren't supported in wasm, where this has no effect.**
+    #[must_use]
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
     }
 
     pub(crate) fn inner(&self) -> &reqwest::Client {
@@ -80,41 +135,127 @@ impl HttpClient for ReqwestClient {
             format!("{url}{query}")
         };
 
-        let mut request = self.client.request(verb(&method), &url);
+        let verb = verb(&method);
+        let mut body = method.into_body();
+
+        // TODO: Currently reqwest doesn't support streaming data in wasm so we need to collect everything in RAM
+        let buffered_body = match body.take() {
+            Some(body) if cfg!(target_arch = "wasm32") => Some(read_body(body).await?),
+            streamed => {
+                body = streamed;
+                None
+            }
+        };
+        // A streamed body can only be read once, the chunks already sent are kept to be sent again by a retry.
+        #[cfg(not(target_arch = "wasm32"))]
+        let replayable_body = match self.retry_policy {
+            Some(_) => body.take().map(ReplayableBody::new),
+            None => None,
+        };
+
+        let mut attempt = 0;
+        loop {
+            let mut request = self.client.request(verb.clone(), &url);
 
-        if let Some(body) = method.into_body() {
-            // TODO: Currently reqwest doesn't support streaming data in wasm so we need to collect everything in RAM
             #[cfg(not(target_arch = "wasm32"))]
-            {
-                let stream = ReaderStream::new(body);
-                let body = reqwest::Body::wrap_stream(stream);
+            if let Some(replayable_body) = &replayable_body {
+                request = request
+                    .header(header::CONTENT_TYPE, content_type)
+                    .body(reqwest::Body::wrap_stream(replayable_body.attempt()));
+            }
 
+            if let Some(buf) = &buffered_body {
                 request = request
                     .header(header::CONTENT_TYPE, content_type)
-                    .body(body);
+                    .body(buf.clone());
+            } else if let Some(body) = body.take() {
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    let stream = ReaderStream::new(body);
+                    request = request
+                        .header(header::CONTENT_TYPE, content_type)
+                        .body(reqwest::Body::wrap_stream(stream));
+                }
+                // bodies are always buffered in wasm
+                #[cfg(target_arch = "wasm32")]
+                let _ = body;
             }
-            #[cfg(target_arch = "wasm32")]
-            {
-                use futures_util::AsyncReadExt;
-
-                let mut buf = Vec::new();
-                let mut body = std::pin::pin!(body);
-                body.read_to_end(&mut buf)
-                    .await
-                    .map_err(|err| Error::Other(Box::new(err)))?;
-                request = request.header(header::CONTENT_TYPE, content_type).body(buf);
+
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(timeout) = self.request_timeout {
+                request = request.timeout(timeout);
             }
-        }
 
-        let response = self.client.execute(request.build()?).await?;
-        let status = response.status().as_u16();
-        let mut body = response.text().await?;
+            let request = request.build()?;
+            // `Instant::now` panics in wasm, where no metrics are recorded
+            #[cfg(all(feature = "metrics", not(target_arch = "wasm32")))]
+            let path = request.url().path().to_string();
+            #[cfg(not(target_arch = "wasm32"))]
+            let started_at = std::time::Instant::now();
 
-        if body.is_empty() {
-            body = "null".to_string();
-        }
+            #[cfg(feature = "log")]
+            debug!("→ {verb} {url}");
+            let response = self.client.execute(request).await;
+            #[cfg(all(feature = "metrics", not(target_arch = "wasm32")))]
+            crate::metrics::observe_request(
+                verb.as_str(),
+                &path,
+                response
+                    .as_ref()
+                    .ok()
+                    .map(|response| response.status().as_u16()),
+                started_at.elapsed(),
+            );
+
+            let response = match response {
+                Ok(response) => response,
+                Err(error) => {
+                    let error = match error {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        error if error.is_timeout() => Error::RequestTimeout {
+                            url: url.clone(),
+                            elapsed: started_at.elapsed(),
+                        },
+                        error => Error::from(error),
+                    };
+                    attempt += 1;
+                    let delay = self
+                        .retry_policy
+                        .as_ref()
+                        .filter(|_| self.can_retry(&verb, &error))
+                        .and_then(|policy| policy.should_retry(attempt, &error));
+
+                    match delay {
+                        Some(delay) => {
+                            SleepBackend::infer(self.is_tokio()).sleep(delay).await;
+                            continue;
+                        }
+                        None => return Err(error),
+                    }
+                }
+            };
+
+            let status = response.status().as_u16();
+            #[cfg(feature = "log")]
+            debug!("← {status} {url}");
+            let mut body = match response.text().await {
+                Ok(body) => body,
+                #[cfg(not(target_arch = "wasm32"))]
+                Err(error) if error.is_timeout() => {
+                    return Err(Error::RequestTimeout {
+                        url,
+                        elapsed: started_at.elapsed(),
+                    })
+                }
+                Err(error) => return Err(error.into()),
+            };
+
+            if body.is_empty() {
+                body = "null".to_string();
+            }
 
-        parse_response(status, expected_status_code, &body, url.to_string())
+            return parse_response(status, expected_status_code, &body, url.to_string());
+        }
     }
 
     fn is_tokio(&self) -> bool {
@@ -122,6 +263,29 @@ impl HttpClient for ReqwestClient {
     }
 }
 
+impl ReqwestClient {
+    /// A request that never reached Meilisearch can always be sent again, the others only if they are idempotent.
+    fn can_retry(&self, verb: &reqwest::Method, error: &Error) -> bool {
+        let connect_error = matches!(error, Error::HttpError(error) if error.is_connect());
+
+        connect_error
+            || self.retry_non_idempotent
+            || matches!(*verb, reqwest::Method::GET | reqwest::Method::DELETE)
+    }
+}
+
+async fn read_body(body: impl AsyncRead) -> Result<bytes::Bytes, Error> {
+    use futures_util::AsyncReadExt;
+
+    let mut buf = Vec::new();
+    let mut body = std::pin::pin!(body);
+    body.read_to_end(&mut buf)
+        .await
+        .map_err(|err| Error::Other(Box::new(err)))?;
+
+    Ok(buf.into())
+}
+
 fn verb<Q, B>(method: &Method<Q, B>) -> reqwest::Method {
     match method {
         Method::Get { .. } => reqwest::Method::GET,
@@ -180,3 +344,118 @@ impl<R: AsyncRead> Stream for ReaderStream<R> {
         }
     }
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+struct Replay<R: AsyncRead> {
+    /// Incremented by each attempt, the streams of the previous attempts stop reading.
+    attempt: u64,
+    sent: Vec<Bytes>,
+    remaining: Option<Pin<Box<ReaderStream<R>>>>,
+}
+
+/// A request body that is streamed, and kept in memory as it's read, so it can be sent again.
+///
+/// Each attempt first replays the chunks read by the previous attempts, then reads the rest of the body.
+#[cfg(not(target_arch = "wasm32"))]
+struct ReplayableBody<R: AsyncRead> {
+    replay: Arc<std::sync::Mutex<Replay<R>>>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<R: AsyncRead> ReplayableBody<R> {
+    fn new(reader: R) -> Self {
+        ReplayableBody {
+            replay: Arc::new(std::sync::Mutex::new(Replay {
+                attempt: 0,
+                sent: Vec::new(),
+                remaining: Some(Box::pin(ReaderStream::new(reader))),
+            })),
+        }
+    }
+
+    fn attempt(&self) -> ReplayStream<R> {
+        let mut replay = self.replay.lock().unwrap();
+        replay.attempt += 1;
+
+        ReplayStream {
+            replay: Arc::clone(&self.replay),
+            attempt: replay.attempt,
+            position: 0,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+struct ReplayStream<R: AsyncRead> {
+    replay: Arc<std::sync::Mutex<Replay<R>>>,
+    attempt: u64,
+    position: usize,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<R: AsyncRead> Stream for ReplayStream<R> {
+    type Item = std::io::Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let replay = Arc::clone(&self.replay);
+        let mut replay = replay.lock().unwrap();
+
+        if replay.attempt != self.attempt {
+            return Poll::Ready(None);
+        }
+        if let Some(chunk) = replay.sent.get(self.position) {
+            let chunk = chunk.clone();
+            self.position += 1;
+            return Poll::Ready(Some(Ok(chunk)));
+        }
+
+        let remaining = match replay.remaining.as_mut() {
+            Some(remaining) => remaining,
+            None => return Poll::Ready(None),
+        };
+        match remaining.as_mut().poll_next(cx) {
+            Poll::Ready(Some(Ok(chunk))) => {
+                replay.sent.push(chunk.clone());
+                self.position += 1;
+                Poll::Ready(Some(Ok(chunk)))
+            }
+            Poll::Ready(None) => {
+                replay.remaining = None;
+                Poll::Ready(None)
+            }
+            poll => poll,
+        }
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+
+    #[tokio::test]
+    async fn test_replayable_body_streams_and_replays_the_chunks() {
+        use futures_util::{io::Cursor, AsyncReadExt};
+
+        // each read returns the bytes of one of the two cursors
+        let reader = Cursor::new(vec![1; 10]).chain(Cursor::new(vec![2; 5]));
+        let body = ReplayableBody::new(reader);
+
+        // the body is only read as it's streamed
+        let mut first = body.attempt();
+        assert_eq!(
+            first.next().await.unwrap().unwrap(),
+            Bytes::from(vec![1; 10])
+        );
+        assert_eq!(body.replay.lock().unwrap().sent.len(), 1);
+
+        // a retry replays what was read then reads the rest, and the previous attempt stops
+        let second = body.attempt();
+        assert!(first.next().await.is_none());
+        let sent: Vec<Bytes> = second.map(|chunk| chunk.unwrap()).collect().await;
+        assert_eq!(sent.concat(), [vec![1; 10], vec![2; 5]].concat());
+
+        let third: Vec<Bytes> = body.attempt().map(|chunk| chunk.unwrap()).collect().await;
+        assert_eq!(third, sent);
+    }
+}
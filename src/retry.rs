@@ -0,0 +1,171 @@
+use std::{convert::TryFrom, time::Duration};
+
+use crate::errors::Error;
+
+/// Decides whether a failed request should be sent again.
+///
+/// See [`Client::with_retry_policy`](crate::client::Client::with_retry_policy).
+pub trait RetryPolicy: Send + Sync {
+    /// Returns how long to wait before retrying, or `None` to give up and return the error.
+    ///
+    /// `attempt` is the number of attempts that already failed, starting at `1`.
+    fn should_retry(&self, attempt: u32, error: &Error) -> Option<Duration>;
+}
+
+/// Retries requests that couldn't connect to Meilisearch (connection refused, DNS failures…) or that timed out,
+/// waiting `initial_delay * multiplier^(attempt - 1)` between each attempt, up to `max_delay`.
+///
+/// Errors returned by Meilisearch itself are never retried.
+/// A request that timed out did reach Meilisearch and may have been processed, so the client only gives the
+/// timeouts of its `GET` and `DELETE` requests to the policy, see [`Client::with_non_idempotent_retries`](crate::client::Client::with_non_idempotent_retries).
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use meilisearch_sdk::retry::ExponentialBackoffRetryPolicy;
+/// let policy = ExponentialBackoffRetryPolicy::new(3, Duration::from_millis(100), 2.0)
+///     .unwrap()
+///     .with_max_delay(Duration::from_secs(1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExponentialBackoffRetryPolicy {
+    max_retries: u32,
+    initial_delay: Duration,
+    multiplier: f64,
+    max_delay: Duration,
+}
+
+impl ExponentialBackoffRetryPolicy {
+    /// The default upper bound of the delay between two attempts.
+    pub const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+    /// Retry at most `max_retries` times, waiting `initial_delay` before the first retry
+    /// and multiplying the delay by `multiplier` after each retry.
+    ///
+    /// Returns [`Error::InvalidArgument`] if `multiplier` isn't a finite number greater than or equal to `1`.
+    pub fn new(
+        max_retries: u32,
+        initial_delay: Duration,
+        multiplier: f64,
+    ) -> Result<ExponentialBackoffRetryPolicy, Error> {
+        if !multiplier.is_finite() || multiplier < 1.0 {
+            return Err(Error::InvalidArgument(format!(
+                "the multiplier of a retry policy must be a finite number of at least 1, got {multiplier}"
+            )));
+        }
+
+        Ok(ExponentialBackoffRetryPolicy {
+            max_retries,
+            initial_delay,
+            multiplier,
+            max_delay: Self::DEFAULT_MAX_DELAY,
+        })
+    }
+
+    /// Never wait more than `max_delay` between two attempts, [`DEFAULT_MAX_DELAY`](Self::DEFAULT_MAX_DELAY) by default.
+    #[must_use]
+    pub fn with_max_delay(mut self, max_delay: Duration) -> ExponentialBackoffRetryPolicy {
+        self.max_delay = max_delay;
+        self
+    }
+}
+
+impl RetryPolicy for ExponentialBackoffRetryPolicy {
+    fn should_retry(&self, attempt: u32, error: &Error) -> Option<Duration> {
+        if attempt == 0 || attempt > self.max_retries || !is_transient(error) {
+            return None;
+        }
+
+        let exponent = i32::try_from(attempt - 1).unwrap_or(i32::MAX);
+        let delay = self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent);
+        // an overflowing delay fails the conversion
+        let delay = Duration::try_from_secs_f64(delay).unwrap_or(self.max_delay);
+        Some(delay.min(self.max_delay))
+    }
+}
+
+fn is_transient(error: &Error) -> bool {
+    match error {
+        #[cfg(feature = "reqwest")]
+        Error::HttpError(error) => error.is_connect() || error.is_timeout(),
+        Error::RequestTimeout { .. } => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exponential_backoff_delays() {
+        let policy = ExponentialBackoffRetryPolicy::new(3, Duration::from_millis(100), 2.0)
+            .unwrap()
+            .with_max_delay(Duration::from_millis(300));
+        let error = Error::Other("connection reset".into());
+
+        // only transport errors are retried
+        assert_eq!(policy.should_retry(1, &error), None);
+        assert_eq!(policy.should_retry(1, &Error::Timeout), None);
+
+        #[cfg(feature = "reqwest")]
+        {
+            // the request was never sent
+            let error = reqwest::Client::new()
+                .get("not a url")
+                .build()
+                .map(|_| ())
+                .unwrap_err();
+            let error = Error::HttpError(error);
+
+            assert_eq!(policy.should_retry(1, &error), None);
+        }
+
+        let error = Error::RequestTimeout {
+            url: "http://localhost:7700/health".to_string(),
+            elapsed: Duration::from_millis(50),
+        };
+        assert_eq!(
+            policy.should_retry(1, &error),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(
+            policy.should_retry(2, &error),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(
+            policy.should_retry(3, &error),
+            Some(Duration::from_millis(300))
+        );
+        assert_eq!(policy.should_retry(4, &error), None);
+    }
+
+    #[test]
+    fn test_exponential_backoff_doesnt_overflow() {
+        let policy = ExponentialBackoffRetryPolicy::new(u32::MAX, Duration::from_secs(1), 10.0)
+            .unwrap()
+            .with_max_delay(Duration::from_secs(60));
+        let error = Error::RequestTimeout {
+            url: "http://localhost:7700/health".to_string(),
+            elapsed: Duration::from_millis(50),
+        };
+
+        for attempt in [100, 1_000, u32::MAX] {
+            assert_eq!(
+                policy.should_retry(attempt, &error),
+                Some(Duration::from_secs(60))
+            );
+        }
+    }
+
+    #[test]
+    fn test_exponential_backoff_rejects_invalid_multipliers() {
+        for multiplier in [0.5, -2.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                ExponentialBackoffRetryPolicy::new(3, Duration::from_millis(100), multiplier),
+                Err(Error::InvalidArgument(_))
+            ));
+        }
+    }
+}
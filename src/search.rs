@@ -8,7 +8,12 @@ use crate::{
 use either::Either;
 use serde::{de::DeserializeOwned, Deserialize, Serialize, Serializer};
 use serde_json::{Map, Value};
-use std::collections::HashMap;
+use std::{collections::HashMap, convert::TryFrom};
+
+/// Number of hits Meilisearch returns when no `limit` is given.
+const DEFAULT_SEARCH_LIMIT: usize = 20;
+/// The default `maxTotalHits` of Meilisearch, above which a search can't return more results.
+const MAX_SEARCH_LIMIT: usize = 1000;
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct MatchRange {
@@ -51,6 +56,19 @@ pub enum MatchingStrategies {
     FREQUENCY,
 }
 
+/// How Meilisearch computes the total number of hits of a search.
+///
+/// See [`SearchQuery::with_total_hits_estimation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HitsEstimationMode {
+    /// Use `offset`/`limit` pagination, the response contains an `estimatedTotalHits`.
+    Estimated,
+    /// Use `page`/`hitsPerPage` pagination, the response contains the exact `totalHits` and `totalPages`.
+    ///
+    /// Computing the exact number of hits is slower than estimating it.
+    Exact,
+}
+
 /// A single result.
 ///
 /// Contains the complete object, optionally the formatted object, and optionally an object that contains information about the matches.
@@ -119,7 +137,7 @@ pub struct SearchResults<T> {
     /// Present when the `exhaustiveFacetCount` search parameter is used.
     pub exhaustive_facet_count: Option<bool>,
     /// Processing time of the query.
-    pub processing_time_ms: usize,
+    pub processing_time_ms: u64,
     /// Query originating the response.
     pub query: String,
     /// Index uid on which the search was made.
@@ -140,6 +158,31 @@ pub struct SearchResults<T> {
     pub performance_details: Option<Value>,
 }
 
+impl<T> SearchResults<T> {
+    /// Returns the documents that matched, dropping the metadata of each hit and of the response.
+    #[must_use]
+    pub fn into_hits(self) -> Vec<T> {
+        self.hits.into_iter().map(|hit| hit.result).collect()
+    }
+
+    /// Iterates over the documents that matched, without the metadata of each hit.
+    ///
+    /// Each document is stored in a [`SearchResult`] along with its metadata, so they can't be borrowed as a `&[T]`.
+    /// The full [`SearchResult`]s are available in the [`hits`](SearchResults#structfield.hits) field.
+    pub fn iter_hits(&self) -> impl Iterator<Item = &T> + '_ {
+        self.hits.iter().map(|hit| &hit.result)
+    }
+}
+
+/// An empty list of attributes to retrieve is left out, Meilisearch then returns all of them.
+fn is_unset_or_empty(data: &Option<Selectors<&[&str]>>) -> bool {
+    match data {
+        None => true,
+        Some(Selectors::Some(data)) => data.is_empty(),
+        Some(Selectors::All) => false,
+    }
+}
+
 fn serialize_attributes_to_crop_with_wildcard<S: Serializer>(
     data: &Option<Selectors<&[AttributeToCrop]>>,
     s: S,
@@ -184,6 +227,49 @@ impl<T: Serialize> Serialize for Selectors<T> {
     }
 }
 
+/// Direction of a [`Sort`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// A sort rule applied to the search results, formatted as `attribute:asc` or `attribute:desc`.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::search::{Sort, SortOrder};
+/// let sort = Sort::new("price", SortOrder::Asc);
+///
+/// assert_eq!(sort.to_string(), "price:asc");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sort {
+    pub attribute: String,
+    pub order: SortOrder,
+}
+
+impl Sort {
+    #[must_use]
+    pub fn new(attribute: impl Into<String>, order: SortOrder) -> Sort {
+        Sort {
+            attribute: attribute.into(),
+            order,
+        }
+    }
+}
+
+impl std::fmt::Display for Sort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let order = match self.order {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+        };
+        write!(f, "{}:{}", self.attribute, order)
+    }
+}
+
 /// Configures Meilisearch to return search results based on a query’s meaning and context
 #[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -309,7 +395,7 @@ pub struct SearchQuery<'a, Http: HttpClient> {
     pub facets: Option<Selectors<&'a [&'a str]>>,
     /// Attributes to sort.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<&'a [&'a str]>,
+    pub sort: Option<Vec<String>>,
     /// Attributes to perform the search on.
     ///
     /// Specify the subset of searchableAttributes for a search without modifying Meilisearch’s index settings.
@@ -321,8 +407,10 @@ pub struct SearchQuery<'a, Http: HttpClient> {
     ///
     /// Can be set to a [wildcard value](enum.Selectors.html#variant.All) that will select all existing attributes.
     ///
+    /// An empty list is not sent, so it also selects all the attributes.
+    ///
     /// **Default: all attributes found in the documents.**
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "is_unset_or_empty")]
     pub attributes_to_retrieve: Option<Selectors<&'a [&'a str]>>,
     /// Attributes whose values have to be cropped.
     ///
@@ -486,6 +574,31 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
         }
     }
 
+    /// Create a query matching every document of the index.
+    ///
+    /// Every optional parameter is left unset, so only an empty `q` is sent to Meilisearch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::Client, search::SearchQuery};
+    /// # let client = Client::new("http://localhost:7700", Some("masterKey")).unwrap();
+    /// let index = client.index("movies");
+    /// let query = SearchQuery::default_for(&index);
+    ///
+    /// assert_eq!(query.query, Some(""));
+    /// ```
+    #[must_use]
+    pub fn default_for(index: &'a Index<Http>) -> SearchQuery<'a, Http> {
+        SearchQuery {
+            query: Some(""),
+            ..SearchQuery::new(index)
+        }
+    }
+
+    /// Set the text to search for.
+    ///
+    /// The query is borrowed for the lifetime of the [`SearchQuery`], so string literals are used without any allocation.
     pub fn with_query<'b>(&'b mut self, query: &'a str) -> &'b mut SearchQuery<'a, Http> {
         self.query = Some(query);
         self
@@ -568,6 +681,54 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
         self
     }
 
+    /// Choose between an estimated and an exact total number of hits.
+    ///
+    /// [`HitsEstimationMode::Exact`] switches the query to the `page`/`hitsPerPage` pagination mode,
+    /// starting on the first page with `limit` (or 20) hits per page if they were not already set.
+    /// The exact count is then available in [`SearchResults::total_hits`] and [`SearchResults::total_pages`].
+    ///
+    /// [`HitsEstimationMode::Estimated`] removes `page` and `hitsPerPage` so `offset`/`limit` are used,
+    /// and the count is available in [`SearchResults::estimated_total_hits`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let index = client.index("search_with_total_hits_estimation");
+    ///
+    /// let mut query = SearchQuery::new(&index);
+    /// query
+    ///     .with_query("harry")
+    ///     .with_total_hits_estimation(HitsEstimationMode::Exact);
+    ///
+    /// assert_eq!(query.page, Some(1));
+    /// assert_eq!(query.hits_per_page, Some(20));
+    /// ```
+    pub fn with_total_hits_estimation<'b>(
+        &'b mut self,
+        mode: HitsEstimationMode,
+    ) -> &'b mut SearchQuery<'a, Http> {
+        match mode {
+            HitsEstimationMode::Estimated => {
+                self.page = None;
+                self.hits_per_page = None;
+            }
+            HitsEstimationMode::Exact => {
+                self.page = Some(self.page.unwrap_or(1));
+                self.hits_per_page = Some(
+                    self.hits_per_page
+                        .unwrap_or_else(|| self.limit.unwrap_or(DEFAULT_SEARCH_LIMIT)),
+                );
+            }
+        }
+        self
+    }
+
     pub fn with_filter<'b>(&'b mut self, filter: &'a str) -> &'b mut SearchQuery<'a, Http> {
         self.filter = Some(Filter::new(Either::Left(filter)));
         self
@@ -598,8 +759,23 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
         self
     }
 
-    pub fn with_sort<'b>(&'b mut self, sort: &'a [&'a str]) -> &'b mut SearchQuery<'a, Http> {
-        self.sort = Some(sort);
+    /// Sort the results with the given rules, applied in order.
+    pub fn with_sort<'b>(
+        &'b mut self,
+        sort: impl IntoIterator<Item = Sort>,
+    ) -> &'b mut SearchQuery<'a, Http> {
+        self.sort = Some(sort.into_iter().map(|sort| sort.to_string()).collect());
+        self
+    }
+
+    /// Sort the results with rules already written in the Meilisearch syntax, like `"price:asc"`.
+    ///
+    /// The rules are checked by [`SearchQuery::validate`].
+    pub fn with_sort_raw<'b>(
+        &'b mut self,
+        sort: impl IntoIterator<Item = impl Into<String>>,
+    ) -> &'b mut SearchQuery<'a, Http> {
+        self.sort = Some(sort.into_iter().map(Into::into).collect());
         self
     }
 
@@ -619,6 +795,9 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
         self
     }
 
+    /// Crop the given attributes, optionally overriding the `crop_length` of each one.
+    ///
+    /// Use [`Selectors::All`] to crop every attribute, it is sent as `["*"]`.
     pub fn with_attributes_to_crop<'b>(
         &'b mut self,
         attributes_to_crop: Selectors<&'a [(&'a str, Option<usize>)]>,
@@ -640,6 +819,9 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
         self
     }
 
+    /// Highlight the matching terms in the given attributes.
+    ///
+    /// Use [`Selectors::All`] to highlight every attribute, it is sent as `["*"]`.
     pub fn with_attributes_to_highlight<'b>(
         &'b mut self,
         attributes_to_highlight: Selectors<&'a [&'a str]>,
@@ -696,6 +878,9 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
         self
     }
 
+    /// Include the uid of the queried index in the query, as required by the multi search routes.
+    ///
+    /// [`MultiSearchQuery::with_search_query`] does it for you, so a standalone [`SearchQuery`] can be built once and added there as is.
     pub fn with_index_uid<'b>(&'b mut self) -> &'b mut SearchQuery<'a, Http> {
         self.index_uid = Some(&self.index.uid);
         self
@@ -753,6 +938,67 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
         self.clone()
     }
 
+    /// Check the query for parameters Meilisearch would reject or ignore, without sending it.
+    ///
+    /// This is done automatically before sending a search, including the queries of a multi search.
+    /// The following are rejected with [`Error::InvalidSearchParameter`]:
+    /// - a `limit` above `1000`, the default `maxTotalHits`,
+    /// - an `offset + limit` that doesn't fit in a `u32`,
+    /// - a sort rule that isn't written `attribute:asc` or `attribute:desc`.
+    pub fn validate(&self) -> Result<(), Error> {
+        for rule in self.sort.iter().flatten() {
+            let is_valid = match rule.rsplit_once(':') {
+                Some((attribute, order)) => {
+                    !attribute.trim().is_empty() && (order == "asc" || order == "desc")
+                }
+                None => false,
+            };
+            if !is_valid {
+                return Err(Error::InvalidSearchParameter(format!(
+                    "sort rules must be written `attribute:asc` or `attribute:desc`, got {rule:?}"
+                )));
+            }
+        }
+
+        if let Some(limit) = self.limit {
+            if limit > MAX_SEARCH_LIMIT {
+                return Err(Error::InvalidSearchParameter(format!(
+                    "limit must be at most {MAX_SEARCH_LIMIT}, got {limit}"
+                )));
+            }
+
+            let offset = self.offset.unwrap_or(0);
+            let end = offset
+                .checked_add(limit)
+                .and_then(|end| u32::try_from(end).ok());
+            if end.is_none() {
+                return Err(Error::InvalidSearchParameter(format!(
+                    "offset + limit must fit in a u32, got {offset} + {limit}"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Serialize the query into the JSON body sent to Meilisearch.
+    ///
+    /// Useful to log what is about to be sent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::Client, search::SearchQuery};
+    /// # let client = Client::new("http://localhost:7700", Some("masterKey")).unwrap();
+    /// let index = client.index("movies");
+    /// let body = SearchQuery::new(&index).with_query("space").to_json_body().unwrap();
+    ///
+    /// assert_eq!(body, r#"{"q":"space"}"#);
+    /// ```
+    pub fn to_json_body(&self) -> Result<String, Error> {
+        Ok(serde_json::to_string(self)?)
+    }
+
     /// Request exhaustive facet count in the response.
     pub fn with_exhaustive_facet_count<'b>(
         &'b mut self,
@@ -772,6 +1018,8 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
     }
 
     /// Execute the query and fetch the results.
+    ///
+    /// The query is [validated](SearchQuery::validate) first.
     pub async fn execute<T: 'static + DeserializeOwned + Send + Sync>(
         &'a self,
     ) -> Result<SearchResults<T>, Error> {
@@ -854,6 +1102,17 @@ impl<'a, 'b, Http: HttpClient> MultiSearchQuery<'a, 'b, Http> {
     ) -> Result<MultiSearchResponse<T>, Error> {
         self.client.execute_multi_search_query::<T>(self).await
     }
+
+    /// Execute the query and fetch the results, keeping the outcome of each query separately.
+    ///
+    /// Unlike [`MultiSearchQuery::execute`], an error returned for one of the queries doesn't make the whole response fail to parse.
+    pub async fn execute_per_query<T: 'static + DeserializeOwned + Send + Sync>(
+        &'a self,
+    ) -> Result<MultiSearchResults<T>, Error> {
+        self.client
+            .execute_multi_search_query_per_query::<T>(self)
+            .await
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -861,6 +1120,67 @@ pub struct MultiSearchResponse<T> {
     pub results: Vec<SearchResults<T>>,
 }
 
+/// The outcome of a single query of a [`MultiSearchQuery`].
+pub type MultiSearchQueryResult<T> = Result<SearchResults<T>, MeilisearchError>;
+
+/// The results of a [`MultiSearchQuery`] where every query succeeded or failed on its own.
+///
+/// Returned by [`MultiSearchQuery::execute_per_query`].
+#[derive(Debug, Clone)]
+pub struct MultiSearchResults<T = Value> {
+    pub results: Vec<MultiSearchQueryResult<T>>,
+}
+
+impl<T> MultiSearchResults<T> {
+    /// Returns the results of all the queries, or the errors of the ones that failed.
+    pub fn all_ok(self) -> Result<Vec<SearchResults<T>>, Vec<MeilisearchError>> {
+        let mut results = Vec::with_capacity(self.results.len());
+        let mut errors = Vec::new();
+
+        for result in self.results {
+            match result {
+                Ok(result) => results.push(result),
+                Err(error) => errors.push(error),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(results)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for MultiSearchResults<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        #[allow(clippy::large_enum_variant)] // Only lives while deserializing
+        enum Entry<T> {
+            Failure(MeilisearchError),
+            Success(SearchResults<T>),
+        }
+
+        #[derive(Deserialize)]
+        struct Raw<T> {
+            results: Vec<Entry<T>>,
+        }
+
+        let raw = Raw::<T>::deserialize(deserializer)?;
+        Ok(MultiSearchResults {
+            results: raw
+                .results
+                .into_iter()
+                .map(|entry| match entry {
+                    Entry::Failure(error) => Err(error),
+                    Entry::Success(result) => Ok(result),
+                })
+                .collect(),
+        })
+    }
+}
+
 #[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct FederatedMultiSearchQuery<'a, 'b, Http: HttpClient = DefaultHttpClient> {
@@ -939,7 +1259,7 @@ pub struct FederatedMultiSearchResponse<T> {
     pub estimated_total_hits: usize,
 
     /// Processing time of the query.
-    pub processing_time_ms: usize,
+    pub processing_time_ms: u64,
 
     /// [Data for facets present in the search results](https://www.meilisearch.com/docs/reference/api/multi_search#facetsbyindex)
     pub facets_by_index: Option<ComputedFacets>,
@@ -1142,7 +1462,7 @@ pub struct FacetHit {
 pub struct FacetSearchResponse {
     pub facet_hits: Vec<FacetHit>,
     pub facet_query: Option<String>,
-    pub processing_time_ms: usize,
+    pub processing_time_ms: u64,
 }
 
 #[cfg(test)]
@@ -1173,7 +1493,7 @@ pub(crate) mod tests {
             }
         }));
 
-        let serialized = serde_json::to_value(&query.build()).unwrap();
+        let serialized = serde_json::to_value(query.build()).unwrap();
 
         assert_eq!(
             serialized.get("media"),
@@ -1362,6 +1682,136 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_multi_search_per_query_errors() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let movies = client.index("movies");
+        let missing = client.index("missing");
+
+        let _m = s
+            .mock("POST", "/multi-search")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "results": [
+                        {
+                            "indexUid": "movies",
+                            "hits": [{ "id": 1 }],
+                            "query": "space",
+                            "processingTimeMs": 1,
+                            "limit": 20,
+                            "offset": 0,
+                            "estimatedTotalHits": 1
+                        },
+                        {
+                            "message": "Index `missing` not found.",
+                            "code": "index_not_found",
+                            "type": "invalid_request",
+                            "link": "https://docs.meilisearch.com/errors#index_not_found"
+                        }
+                    ]
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let mut multi_query = client.multi_search();
+        multi_query.with_search_query(SearchQuery::new(&movies).with_query("space").build());
+        multi_query.with_search_query(SearchQuery::new(&missing).with_query("space").build());
+        let response = multi_query.execute_per_query::<Value>().await?;
+
+        assert_eq!(response.results.len(), 2);
+        assert_eq!(response.results[0].as_ref().unwrap().hits.len(), 1);
+        assert!(matches!(
+            response.results[1],
+            Err(MeilisearchError {
+                error_code: crate::errors::ErrorCode::IndexNotFound,
+                ..
+            })
+        ));
+
+        let errors = response.all_ok().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error_message, "Index `missing` not found.");
+        Ok(())
+    }
+
+    #[test]
+    fn test_multi_search_results_all_ok() {
+        let response: MultiSearchResults = serde_json::from_value(json!({
+            "results": [
+                { "hits": [], "query": "a", "processingTimeMs": 0 },
+                { "hits": [{ "id": 1 }], "query": "b", "processingTimeMs": 0 }
+            ]
+        }))
+        .unwrap();
+
+        let results = response.all_ok().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1].hits.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_federated_multi_search_flattens_hits() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let movies = client.index("movies");
+        let series = client.index("series");
+
+        let mock = s
+            .mock("POST", "/multi-search")
+            .match_body(mockito::Matcher::Json(json!({
+                "queries": [
+                    { "indexUid": "movies", "q": "space" },
+                    { "indexUid": "series", "q": "space" }
+                ],
+                "federation": { "offset": 0, "limit": 2 }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "hits": [
+                        { "id": 1, "_federation": { "indexUid": "movies", "queriesPosition": 0, "weightedRankingScore": 0.9 } },
+                        { "id": 7, "_federation": { "indexUid": "series", "queriesPosition": 1, "weightedRankingScore": 0.8 } }
+                    ],
+                    "offset": 0,
+                    "limit": 2,
+                    "estimatedTotalHits": 2,
+                    "processingTimeMs": 1
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let mut multi_query = client.multi_search();
+        multi_query.with_search_query(SearchQuery::new(&movies).with_query("space").build());
+        multi_query.with_search_query(SearchQuery::new(&series).with_query("space").build());
+        let response = multi_query
+            .with_federation(FederationOptions {
+                offset: Some(0),
+                limit: Some(2),
+                ..Default::default()
+            })
+            .execute::<Value>()
+            .await?;
+
+        let sources = response
+            .hits
+            .iter()
+            .map(|hit| hit.federation.as_ref().unwrap().index_uid.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(sources, ["movies", "series"]);
+        assert_eq!(response.hits[1].result["id"], json!(7));
+
+        mock.assert_async().await;
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_federated_multi_search(
         client: Client,
@@ -1663,7 +2113,7 @@ pub(crate) mod tests {
 
         let mut query = SearchQuery::new(&index);
         query.with_query("harry potter");
-        query.with_sort(&["title:desc"]);
+        query.with_sort([Sort::new("title", SortOrder::Desc)]);
         let results: SearchResults<Document> = index.execute_query(&query).await?;
         assert_eq!(results.hits.len(), 7);
         Ok(())
@@ -2074,15 +2524,10 @@ pub(crate) mod tests {
                 assert!(results.exhaustive_facet_count.is_some());
                 Ok(())
             }
-            Err(error)
-                if matches!(
-                    error,
-                    Error::Meilisearch(MeilisearchError {
-                        error_code: ErrorCode::BadRequest,
-                        ..
-                    })
-                ) =>
-            {
+            Err(Error::Meilisearch(MeilisearchError {
+                error_code: ErrorCode::BadRequest,
+                ..
+            })) => {
                 // Server doesn't support this field on /search yet; treat as a skip.
                 Ok(())
             }
@@ -2090,6 +2535,397 @@ pub(crate) mod tests {
         }
     }
 
+    #[test]
+    fn test_search_query_serialization_crop_and_highlight_wildcard() {
+        let client = Client::new("http://localhost:7700", Some("masterKey")).unwrap();
+        let index = client.index("crop_and_highlight_wildcard");
+
+        let mut query = SearchQuery::new(&index);
+        query
+            .with_attributes_to_crop(Selectors::All)
+            .with_attributes_to_highlight(Selectors::All);
+
+        let serialized = serde_json::to_value(query.build()).unwrap();
+        assert_eq!(serialized.get("attributesToCrop"), Some(&json!(["*"])));
+        assert_eq!(serialized.get("attributesToHighlight"), Some(&json!(["*"])));
+
+        query
+            .with_attributes_to_crop(Selectors::Some(&[("*", None)]))
+            .with_attributes_to_highlight(Selectors::Some(&["*"]));
+
+        let serialized = serde_json::to_value(query.build()).unwrap();
+        assert_eq!(serialized.get("attributesToCrop"), Some(&json!(["*"])));
+        assert_eq!(serialized.get("attributesToHighlight"), Some(&json!(["*"])));
+    }
+
+    #[test]
+    fn test_search_query_serialization_total_hits_estimation() {
+        let client = Client::new("http://localhost:7700", Some("masterKey")).unwrap();
+        let index = client.index("total_hits_estimation");
+
+        let mut query = SearchQuery::new(&index);
+        query
+            .with_limit(5)
+            .with_total_hits_estimation(HitsEstimationMode::Exact);
+
+        let exact = serde_json::to_value(query.build()).unwrap();
+        assert_eq!(exact.get("page"), Some(&json!(1)));
+        assert_eq!(exact.get("hitsPerPage"), Some(&json!(5)));
+
+        query.with_total_hits_estimation(HitsEstimationMode::Estimated);
+
+        let estimated = serde_json::to_value(query.build()).unwrap();
+        assert_eq!(estimated.get("page"), None);
+        assert_eq!(estimated.get("hitsPerPage"), None);
+        assert_eq!(estimated.get("limit"), Some(&json!(5)));
+    }
+
+    #[tokio::test]
+    async fn test_execute_same_query_into_different_types() -> Result<(), Error> {
+        #[derive(Debug, Deserialize)]
+        struct Title {
+            title: String,
+        }
+
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let index = client.index("movies");
+
+        let mock = s
+            .mock("POST", "/indexes/movies/search")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"hits":[{"id":1,"title":"Interstellar"}],"query":"space","processingTimeMs":1,"limit":20,"offset":0,"estimatedTotalHits":1}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let mut query = SearchQuery::new(&index);
+        query.with_query("space");
+
+        let titles = query.execute::<Title>().await?;
+        assert_eq!(titles.hits[0].result.title, "Interstellar");
+
+        let raw = query.execute::<Value>().await?;
+        assert_eq!(
+            raw.hits[0].result,
+            json!({"id": 1, "title": "Interstellar"})
+        );
+
+        mock.assert_async().await;
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_query_validate_limit() {
+        let client = Client::new("http://localhost:7700", Some("masterKey")).unwrap();
+        let index = client.index("validate_limit");
+
+        for limit in [999, 1000] {
+            assert!(SearchQuery::new(&index)
+                .with_limit(limit)
+                .validate()
+                .is_ok());
+        }
+
+        assert!(matches!(
+            SearchQuery::new(&index).with_limit(1001).validate(),
+            Err(Error::InvalidSearchParameter(_))
+        ));
+        assert!(matches!(
+            SearchQuery::new(&index)
+                .with_offset(u32::MAX as usize)
+                .with_limit(1)
+                .validate(),
+            Err(Error::InvalidSearchParameter(_))
+        ));
+        assert!(matches!(
+            SearchQuery::new(&index)
+                .with_offset(usize::MAX)
+                .with_limit(1)
+                .validate(),
+            Err(Error::InvalidSearchParameter(_))
+        ));
+        assert!(SearchQuery::new(&index)
+            .with_offset(u32::MAX as usize - 1000)
+            .with_limit(1000)
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_search_query_to_json_body_round_trip() {
+        let client = Client::new("http://localhost:7700", Some("masterKey")).unwrap();
+        let index = client.index("to_json_body");
+
+        let mut query = SearchQuery::new(&index);
+        query
+            .with_query("space")
+            .with_offset(2)
+            .with_limit(5)
+            .with_filter("genre = sci-fi")
+            .with_sort_raw(["year:desc"])
+            .with_show_ranking_score(true);
+
+        let body = query.to_json_body().unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&body).unwrap();
+
+        assert_eq!(
+            parsed,
+            json!({
+                "q": "space",
+                "offset": 2,
+                "limit": 5,
+                "filter": "genre = sci-fi",
+                "sort": ["year:desc"],
+                "showRankingScore": true
+            })
+        );
+    }
+
+    #[test]
+    fn test_processing_time_exceeding_u32() {
+        let results: SearchResults<Value> = serde_json::from_value(json!({
+            "hits": [],
+            "query": "",
+            "processingTimeMs": 5_000_000_000u64,
+        }))
+        .unwrap();
+
+        assert_eq!(results.processing_time_ms, 5_000_000_000);
+        assert!(results.processing_time_ms > u64::from(u32::MAX));
+    }
+
+    #[test]
+    fn test_with_query_borrows_the_query() {
+        let client = Client::new("http://localhost:7700", Some("masterKey")).unwrap();
+        let index = client.index("with_query");
+        let text = "hello";
+
+        let mut query = SearchQuery::new(&index);
+        query.with_query(text);
+
+        assert!(std::ptr::eq(query.query.unwrap(), text));
+    }
+
+    #[test]
+    fn test_search_query_with_index_uid() {
+        let client = Client::new("http://localhost:7700", Some("masterKey")).unwrap();
+        let movies = client.index("movies");
+
+        let mut query = SearchQuery::new(&movies);
+        query.with_query("space");
+        assert_eq!(
+            serde_json::to_value(&query).unwrap(),
+            json!({ "q": "space" })
+        );
+
+        query.with_index_uid();
+        assert_eq!(
+            serde_json::to_value(&query).unwrap(),
+            json!({ "q": "space", "indexUid": "movies" })
+        );
+
+        let standalone = SearchQuery::new(&movies).with_query("alien").build();
+        let mut multi_query = client.multi_search();
+        multi_query.with_search_query(standalone);
+        assert_eq!(
+            serde_json::to_value(&multi_query).unwrap(),
+            json!({ "queries": [{ "q": "alien", "indexUid": "movies" }] })
+        );
+    }
+
+    #[cfg(not(feature = "futures-unsend"))]
+    #[tokio::test]
+    async fn test_search_future_is_send() {
+        let client = Client::new("http://localhost:7700", Some("masterKey")).unwrap();
+        let index = client.index("send_search");
+
+        // only checks that the futures can be spawned on a multi-threaded runtime
+        let handle = tokio::spawn(async move {
+            let query = SearchQuery::new(&index).with_query("space").build();
+            index.execute_query::<Value>(&query).await?;
+            query.execute::<Value>().await
+        });
+        handle.abort();
+    }
+
+    #[test]
+    fn test_search_results_hits_shorthands() {
+        let results: SearchResults<Value> = serde_json::from_value(json!({
+            "hits": [
+                { "id": 1, "_rankingScore": 0.9 },
+                { "id": 2, "_rankingScore": 0.5 }
+            ],
+            "query": "space",
+            "processingTimeMs": 1
+        }))
+        .unwrap();
+
+        assert_eq!(
+            results.iter_hits().collect::<Vec<_>>(),
+            vec![&json!({ "id": 1 }), &json!({ "id": 2 })]
+        );
+        assert_eq!(
+            results.into_hits(),
+            vec![json!({ "id": 1 }), json!({ "id": 2 })]
+        );
+    }
+
+    #[test]
+    fn test_default_search_query_sends_no_extra_fields() {
+        let client = Client::new("http://localhost:7700", Some("masterKey")).unwrap();
+        let index = client.index("movies");
+
+        let query = SearchQuery::new(&index);
+        assert_eq!(serde_json::to_value(&query).unwrap(), json!({}));
+
+        let query = SearchQuery::new(&index).with_query("").build();
+        assert_eq!(serde_json::to_value(&query).unwrap(), json!({ "q": "" }));
+
+        // an explicit `false` is still sent
+        let query = SearchQuery::new(&index)
+            .with_query("")
+            .with_show_ranking_score(false)
+            .build();
+        assert_eq!(
+            serde_json::to_value(&query).unwrap(),
+            json!({ "q": "", "showRankingScore": false })
+        );
+    }
+
+    #[test]
+    fn test_search_query_validate_sort() {
+        let client = Client::new("http://localhost:7700", Some("masterKey")).unwrap();
+        let index = client.index("validate_sort");
+
+        for rule in ["", "price", "price:sideways", ":asc"] {
+            assert!(
+                matches!(
+                    SearchQuery::new(&index).with_sort_raw([rule]).validate(),
+                    Err(Error::InvalidSearchParameter(_))
+                ),
+                "{:?} should be rejected",
+                rule
+            );
+        }
+
+        for rule in ["price:asc", "price:desc", "_geoPoint(48.8566, 2.3522):asc"] {
+            assert!(SearchQuery::new(&index)
+                .with_sort_raw([rule])
+                .validate()
+                .is_ok());
+        }
+        assert!(SearchQuery::new(&index)
+            .with_sort([Sort::new("price", SortOrder::Desc)])
+            .validate()
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_every_search_entry_point_validates_the_query() {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let index = client.index("movies");
+        let search = s
+            .mock("POST", mockito::Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+
+        let mut query = SearchQuery::new(&index);
+        query.with_sort_raw(["price:sideways"]);
+        let is_rejected =
+            |result: Result<(), Error>| matches!(result, Err(Error::InvalidSearchParameter(_)));
+
+        assert!(is_rejected(
+            index.execute_query::<Value>(&query).await.map(|_| ())
+        ));
+        assert!(is_rejected(
+            crate::indexes::TypedIndex::<Value>::new(index.clone())
+                .search(&query)
+                .await
+                .map(|_| ())
+        ));
+
+        let mut multi_search = client.multi_search();
+        multi_search.with_search_query(query.clone());
+        assert!(is_rejected(
+            multi_search.execute::<Value>().await.map(|_| ())
+        ));
+        assert!(is_rejected(
+            multi_search.execute_per_query::<Value>().await.map(|_| ())
+        ));
+        assert!(is_rejected(
+            multi_search
+                .with_federation(FederationOptions::default())
+                .execute::<Value>()
+                .await
+                .map(|_| ())
+        ));
+
+        search.assert_async().await;
+    }
+
+    #[test]
+    fn test_attributes_to_retrieve_serialization() {
+        let client = Client::new("http://localhost:7700", Some("masterKey")).unwrap();
+        let index = client.index("movies");
+
+        for selectors in [Selectors::All, Selectors::Some(&["*"][..])] {
+            let query = SearchQuery::new(&index)
+                .with_attributes_to_retrieve(selectors)
+                .build();
+            assert_eq!(
+                serde_json::to_value(&query).unwrap(),
+                json!({ "attributesToRetrieve": ["*"] })
+            );
+        }
+
+        let query = SearchQuery::new(&index)
+            .with_attributes_to_retrieve(Selectors::Some(&[]))
+            .build();
+        assert_eq!(serde_json::to_value(&query).unwrap(), json!({}));
+
+        let query = SearchQuery::new(&index);
+        assert_eq!(serde_json::to_value(&query).unwrap(), json!({}));
+    }
+
+    #[test]
+    fn test_typed_sort() {
+        let client = Client::new("http://localhost:7700", Some("masterKey")).unwrap();
+        let index = client.index("typed_sort");
+
+        assert_eq!(Sort::new("price", SortOrder::Asc).to_string(), "price:asc");
+        assert_eq!(Sort::new("year", SortOrder::Desc).to_string(), "year:desc");
+
+        let mut query = SearchQuery::new(&index);
+        query.with_sort([
+            Sort::new("price", SortOrder::Asc),
+            Sort::new("year", SortOrder::Desc),
+        ]);
+        assert_eq!(
+            serde_json::to_value(&query).unwrap(),
+            json!({ "sort": ["price:asc", "year:desc"] })
+        );
+
+        query.with_sort_raw(["_geoPoint(48.8, 2.3):asc"]);
+        assert_eq!(
+            serde_json::to_value(&query).unwrap(),
+            json!({ "sort": ["_geoPoint(48.8, 2.3):asc"] })
+        );
+    }
+
+    #[test]
+    fn test_search_query_default_for_only_serializes_q() {
+        let client = Client::new("http://localhost:7700", Some("masterKey")).unwrap();
+        let index = client.index("default_for");
+
+        let query = SearchQuery::default_for(&index);
+
+        assert_eq!(serde_json::to_value(&query).unwrap(), json!({ "q": "" }));
+    }
+
     #[test]
     fn test_search_query_serialization_exhaustive_facet_count() {
         // Build a query and ensure it serializes using the expected camelCase field name
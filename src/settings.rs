@@ -1,3 +1,15 @@
+//! The `settings` module allows the configuration of the [indexes](crate::indexes::Index).
+//!
+//! # Read-modify-write helpers
+//!
+//! Some methods of an [`Index`](crate::indexes::Index), like [`add_synonym`](crate::indexes::Index::add_synonym)
+//! or [`add_stop_word`](crate::indexes::Index::add_stop_word), change a setting from its current value:
+//! they fetch the setting, update it and send the whole setting back.
+//!
+//! The fetch and the update aren't atomic. An update of the same setting that isn't processed yet when one of
+//! these methods is called, including one enqueued by a previous call, is overwritten and lost.
+//! Wait for the returned [task](crate::task_info::TaskInfo) to complete before calling the next one.
+
 use crate::{
     errors::Error,
     indexes::Index,
@@ -5,7 +17,10 @@ use crate::{
     task_info::TaskInfo,
 };
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, HashMap};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    time::Duration,
+};
 
 #[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq, Copy)]
 #[serde(rename_all = "camelCase")]
@@ -116,6 +131,42 @@ impl From<&str> for FilterableAttribute {
     }
 }
 
+/// The [displayed attributes](https://www.meilisearch.com/docs/reference/api/settings#displayed-attributes) of an index.
+///
+/// Meilisearch represents [`DisplayedAttributes::All`] with the `["*"]` wildcard.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum DisplayedAttributes {
+    /// Every attribute of the documents is displayed.
+    All,
+    /// Only the listed attributes are displayed.
+    Fields(Vec<String>),
+}
+
+impl Serialize for DisplayedAttributes {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        match self {
+            DisplayedAttributes::All => ["*"].serialize(s),
+            DisplayedAttributes::Fields(fields) => fields.serialize(s),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DisplayedAttributes {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        Vec::<String>::deserialize(d).map(DisplayedAttributes::from)
+    }
+}
+
+impl From<Vec<String>> for DisplayedAttributes {
+    fn from(fields: Vec<String>) -> Self {
+        if matches!(fields.as_slice(), [wildcard] if wildcard == "*") {
+            DisplayedAttributes::All
+        } else {
+            DisplayedAttributes::Fields(fields)
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Default, Debug, Clone, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub enum EmbedderSource {
@@ -229,18 +280,18 @@ pub struct LocalizedAttributes {
 ///
 /// ```
 /// # use meilisearch_sdk::settings::Settings;
+/// # use std::collections::HashSet;
 /// let settings = Settings::new()
 ///     .with_stop_words(["a", "the", "of"]);
 ///
 /// // OR
 ///
-/// let stop_words: Vec<String> = vec!["a".to_string(), "the".to_string(), "of".to_string()];
+/// let stop_words: HashSet<String> = ["a", "the", "of"].iter().map(|word| word.to_string()).collect();
 /// let mut settings = Settings::new();
-/// settings.stop_words = Some(stop_words);
+/// settings.stop_words = Some(stop_words.clone());
 ///
 /// // OR
 ///
-/// let stop_words: Vec<String> = vec!["a".to_string(), "the".to_string(), "of".to_string()];
 /// let settings = Settings {
 ///     stop_words: Some(stop_words),
 ///     ..Settings::new()
@@ -254,7 +305,7 @@ pub struct Settings {
     pub synonyms: Option<HashMap<String, Vec<String>>>,
     /// List of words ignored by Meilisearch when present in search queries.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_words: Option<Vec<String>>,
+    pub stop_words: Option<HashSet<String>>,
     /// List of [ranking rules](https://www.meilisearch.com/docs/learn/core_concepts/relevancy#order-of-the-rules) sorted by order of importance.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ranking_rules: Option<Vec<String>>,
@@ -274,7 +325,7 @@ pub struct Settings {
     pub searchable_attributes: Option<Vec<String>>,
     /// Fields displayed in the returned documents.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub displayed_attributes: Option<Vec<String>>,
+    pub displayed_attributes: Option<DisplayedAttributes>,
     /// Pagination settings.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pagination: Option<PaginationSetting>,
@@ -307,6 +358,69 @@ pub struct Settings {
     pub localized_attributes: Option<Vec<LocalizedAttributes>>,
 }
 
+/// The [Settings] of an [Index] as returned by Meilisearch.
+///
+/// Unlike [Settings], which only holds the settings to update, every setting is present since the server fills in the defaults.
+/// Fields that can be `null` on the server side are still wrapped in an [Option].
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::settings::{Settings, SettingsView};
+/// # fn convert(view: SettingsView) {
+/// let settings: Settings = view.into();
+/// # }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsView {
+    pub synonyms: HashMap<String, Vec<String>>,
+    pub stop_words: HashSet<String>,
+    pub ranking_rules: Vec<String>,
+    pub filterable_attributes: Vec<FilterableAttribute>,
+    pub sortable_attributes: Vec<String>,
+    pub distinct_attribute: Option<String>,
+    pub searchable_attributes: Vec<String>,
+    pub displayed_attributes: DisplayedAttributes,
+    pub pagination: PaginationSetting,
+    pub faceting: FacetingSettings,
+    pub typo_tolerance: TypoToleranceSettings,
+    pub dictionary: Vec<String>,
+    pub proximity_precision: String,
+    /// Not sent by servers without any embedder configured on older Meilisearch versions.
+    #[serde(default)]
+    pub embedders: HashMap<String, Embedder>,
+    pub search_cutoff_ms: Option<u64>,
+    pub separator_tokens: Vec<String>,
+    pub non_separator_tokens: Vec<String>,
+    pub localized_attributes: Option<Vec<LocalizedAttributes>>,
+}
+
+impl From<SettingsView> for Settings {
+    fn from(view: SettingsView) -> Settings {
+        Settings {
+            synonyms: Some(view.synonyms),
+            stop_words: Some(view.stop_words),
+            ranking_rules: Some(view.ranking_rules),
+            filterable_attributes: Some(view.filterable_attributes),
+            sortable_attributes: Some(view.sortable_attributes),
+            distinct_attribute: Some(view.distinct_attribute),
+            searchable_attributes: Some(view.searchable_attributes),
+            displayed_attributes: Some(view.displayed_attributes),
+            pagination: Some(view.pagination),
+            faceting: Some(view.faceting),
+            typo_tolerance: Some(view.typo_tolerance),
+            dictionary: Some(view.dictionary),
+            proximity_precision: Some(view.proximity_precision),
+            embedders: Some(view.embedders),
+            search_cutoff_ms: view.search_cutoff_ms,
+            separator_tokens: Some(view.separator_tokens),
+            non_separator_tokens: Some(view.non_separator_tokens),
+            localized_attributes: view.localized_attributes,
+        }
+    }
+}
+
 #[allow(missing_docs)]
 impl Settings {
     /// Create undefined settings.
@@ -315,6 +429,46 @@ impl Settings {
         Self::default()
     }
 
+    /// Merge two [Settings], the settings defined in `other` overriding the ones of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::Settings;
+    /// let defaults = Settings::new()
+    ///     .with_ranking_rules(["words", "typo"])
+    ///     .with_stop_words(["the"]);
+    /// let overrides = Settings::new().with_stop_words(["a"]);
+    ///
+    /// let settings = defaults.merge(overrides);
+    ///
+    /// assert_eq!(settings.ranking_rules, Some(vec!["words".to_string(), "typo".to_string()]));
+    /// assert_eq!(settings.stop_words, Some(["a".to_string()].into()));
+    /// ```
+    #[must_use]
+    pub fn merge(self, other: Settings) -> Settings {
+        Settings {
+            synonyms: other.synonyms.or(self.synonyms),
+            stop_words: other.stop_words.or(self.stop_words),
+            ranking_rules: other.ranking_rules.or(self.ranking_rules),
+            filterable_attributes: other.filterable_attributes.or(self.filterable_attributes),
+            sortable_attributes: other.sortable_attributes.or(self.sortable_attributes),
+            distinct_attribute: other.distinct_attribute.or(self.distinct_attribute),
+            searchable_attributes: other.searchable_attributes.or(self.searchable_attributes),
+            displayed_attributes: other.displayed_attributes.or(self.displayed_attributes),
+            pagination: other.pagination.or(self.pagination),
+            faceting: other.faceting.or(self.faceting),
+            typo_tolerance: other.typo_tolerance.or(self.typo_tolerance),
+            dictionary: other.dictionary.or(self.dictionary),
+            proximity_precision: other.proximity_precision.or(self.proximity_precision),
+            embedders: other.embedders.or(self.embedders),
+            search_cutoff_ms: other.search_cutoff_ms.or(self.search_cutoff_ms),
+            separator_tokens: other.separator_tokens.or(self.separator_tokens),
+            non_separator_tokens: other.non_separator_tokens.or(self.non_separator_tokens),
+            localized_attributes: other.localized_attributes.or(self.localized_attributes),
+        }
+    }
+
     #[must_use]
     pub fn with_synonyms<S, U, V>(self, synonyms: HashMap<S, U>) -> Settings
     where
@@ -463,12 +617,12 @@ impl Settings {
         displayed_attributes: impl IntoIterator<Item = impl AsRef<str>>,
     ) -> Settings {
         Settings {
-            displayed_attributes: Some(
+            displayed_attributes: Some(DisplayedAttributes::from(
                 displayed_attributes
                     .into_iter()
                     .map(|v| v.as_ref().to_string())
-                    .collect(),
-            ),
+                    .collect::<Vec<_>>(),
+            )),
             ..self
         }
     }
@@ -595,6 +749,74 @@ impl Settings {
     }
 }
 
+/// A builder for the [synonyms](https://www.meilisearch.com/docs/learn/relevancy/synonyms) of an [Index].
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::settings::SynonymsBuilder;
+/// let synonyms = SynonymsBuilder::new()
+///     .add_synonym("wolverine", &["logan", "xmen"])
+///     .add_one_way("wow", &["world of warcraft"])
+///     .build();
+///
+/// assert_eq!(synonyms["logan"], ["wolverine", "xmen"]);
+/// assert_eq!(synonyms["wow"], ["world of warcraft"]);
+/// assert!(!synonyms.contains_key("world of warcraft"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SynonymsBuilder {
+    synonyms: HashMap<String, Vec<String>>,
+}
+
+impl SynonymsBuilder {
+    #[must_use]
+    pub fn new() -> SynonymsBuilder {
+        Self::default()
+    }
+
+    /// Make `word` and all the `synonyms` synonyms of each other.
+    #[must_use]
+    pub fn add_synonym(mut self, word: &str, synonyms: &[&str]) -> SynonymsBuilder {
+        let words: Vec<&str> = std::iter::once(word)
+            .chain(synonyms.iter().copied())
+            .collect();
+
+        for word in &words {
+            self.insert(word, &words);
+        }
+        self
+    }
+
+    /// Make `word` match the `equivalents`, without making the `equivalents` match `word`.
+    #[must_use]
+    pub fn add_one_way(mut self, word: &str, equivalents: &[&str]) -> SynonymsBuilder {
+        self.insert(word, equivalents);
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> HashMap<String, Vec<String>> {
+        self.synonyms
+    }
+
+    fn insert(&mut self, word: &str, synonyms: &[&str]) {
+        let entry = self.synonyms.entry(word.to_string()).or_default();
+
+        for synonym in synonyms {
+            if *synonym != word && !entry.iter().any(|existing| existing == synonym) {
+                entry.push(synonym.to_string());
+            }
+        }
+    }
+}
+
+impl From<HashMap<String, Vec<String>>> for SynonymsBuilder {
+    fn from(synonyms: HashMap<String, Vec<String>>) -> Self {
+        SynonymsBuilder { synonyms }
+    }
+}
+
 impl<Http: HttpClient> Index<Http> {
     /// Get [Settings] of the [Index].
     ///
@@ -626,6 +848,38 @@ impl<Http: HttpClient> Index<Http> {
             .await
     }
 
+    /// Get all the settings of the [Index] as a [SettingsView].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("get_settings_view", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_settings_view");
+    ///
+    /// let settings = index.get_settings_view().await.unwrap();
+    ///
+    /// assert!(!settings.ranking_rules.is_empty());
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_settings_view(&self) -> Result<SettingsView, Error> {
+        self.client
+            .http_client
+            .request::<(), (), SettingsView>(
+                &format!("{}/indexes/{}/settings", self.client.host, self.uid),
+                Method::Get { query: () },
+                200,
+            )
+            .await
+    }
+
     /// Get [synonyms](https://www.meilisearch.com/docs/reference/api/settings#get-synonyms) of the [Index].
     ///
     /// # Example
@@ -937,10 +1191,10 @@ impl<Http: HttpClient> Index<Http> {
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn get_displayed_attributes(&self) -> Result<Vec<String>, Error> {
+    pub async fn get_displayed_attributes(&self) -> Result<DisplayedAttributes, Error> {
         self.client
             .http_client
-            .request::<(), (), Vec<String>>(
+            .request::<(), (), DisplayedAttributes>(
                 &format!(
                     "{}/indexes/{}/settings/displayed-attributes",
                     self.client.host, self.uid
@@ -1408,6 +1662,79 @@ impl<Http: HttpClient> Index<Http> {
             .await
     }
 
+    /// Update the settings of the [Index] by filling an empty [Settings] with a closure.
+    ///
+    /// Like [`Index::set_settings`], only the fields set by the closure are updated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("update_settings_with", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("update_settings_with");
+    ///
+    /// let task = index
+    ///     .update_settings_with(|settings| {
+    ///         settings.ranking_rules = Some(vec![String::from("words"), String::from("typo")]);
+    ///         settings.distinct_attribute = Some(Some(String::from("title")));
+    ///     })
+    ///     .await
+    ///     .unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn update_settings_with<F: FnOnce(&mut Settings)>(
+        &self,
+        f: F,
+    ) -> Result<TaskInfo, Error> {
+        let mut settings = Settings::new();
+        f(&mut settings);
+        self.set_settings(&settings).await
+    }
+
+    /// Update the settings of the [Index] like [`Index::set_settings`] and wait for the update to be processed.
+    ///
+    /// Returns the error of the update task if it failed, or [`Error::Timeout`] if it wasn't processed within `timeout`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("update_settings_and_wait", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("update_settings_and_wait");
+    ///
+    /// let settings = Settings::new().with_filterable_attributes(["genre"]);
+    /// index
+    ///     .update_settings_and_wait(&settings, Duration::from_secs(5))
+    ///     .await
+    ///     .unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn update_settings_and_wait(
+        &self,
+        settings: &Settings,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        self.set_settings(settings)
+            .await?
+            .wait_for_success(&self.client, timeout)
+            .await
+    }
+
     /// Update [synonyms](https://www.meilisearch.com/docs/reference/api/settings#synonyms) of the [Index].
     ///
     /// # Example
@@ -1452,6 +1779,37 @@ impl<Http: HttpClient> Index<Http> {
             .await
     }
 
+    /// Make `word` and all the `synonyms` synonyms of each other, keeping the existing [synonyms](https://www.meilisearch.com/docs/reference/api/settings#synonyms) of the [Index].
+    ///
+    /// The current synonyms are fetched, merged with the new ones and sent back. Adding the same synonyms twice doesn't change anything.
+    ///
+    /// **Note: the fetch and the update aren't atomic, see the [read-modify-write helpers](crate::settings#read-modify-write-helpers).**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("add_synonym", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("add_synonym");
+    ///
+    /// let task = index.add_synonym("wolverine", &["logan", "xmen"]).await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn add_synonym(&self, word: &str, synonyms: &[&str]) -> Result<TaskInfo, Error> {
+        let synonyms = SynonymsBuilder::from(self.get_synonyms().await?)
+            .add_synonym(word, synonyms)
+            .build();
+
+        self.set_synonyms(&synonyms).await
+    }
+
     /// Update [pagination](https://www.meilisearch.com/docs/reference/api/settings#pagination) of the [Index].
     ///
     /// # Example
@@ -1532,6 +1890,68 @@ impl<Http: HttpClient> Index<Http> {
             .await
     }
 
+    /// Add a word to the [stop-words](https://www.meilisearch.com/docs/reference/api/settings#stop-words) of the [Index].
+    ///
+    /// The current stop words are fetched and sent back with `word`, which is only added if it isn't already a stop word.
+    ///
+    /// **Note: the fetch and the update aren't atomic, see the [read-modify-write helpers](crate::settings#read-modify-write-helpers).**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("add_stop_word", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("add_stop_word");
+    ///
+    /// let task = index.add_stop_word("the").await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn add_stop_word(&self, word: &str) -> Result<TaskInfo, Error> {
+        let mut stop_words = self.get_stop_words().await?;
+        if !stop_words.iter().any(|stop_word| stop_word == word) {
+            stop_words.push(word.to_string());
+        }
+
+        self.set_stop_words(stop_words).await
+    }
+
+    /// Remove a word from the [stop-words](https://www.meilisearch.com/docs/reference/api/settings#stop-words) of the [Index].
+    ///
+    /// The current stop words are fetched and sent back without `word`.
+    ///
+    /// **Note: the fetch and the update aren't atomic, see the [read-modify-write helpers](crate::settings#read-modify-write-helpers).**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("remove_stop_word", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("remove_stop_word");
+    ///
+    /// let task = index.remove_stop_word("the").await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn remove_stop_word(&self, word: &str) -> Result<TaskInfo, Error> {
+        let mut stop_words = self.get_stop_words().await?;
+        stop_words.retain(|stop_word| stop_word != word);
+
+        self.set_stop_words(stop_words).await
+    }
+
     /// Update [ranking rules](https://www.meilisearch.com/docs/reference/api/settings#ranking-rules) of the [Index].
     ///
     /// # Example
@@ -1649,6 +2069,63 @@ impl<Http: HttpClient> Index<Http> {
             .await
     }
 
+    /// Add an attribute to the [filterable attributes](https://www.meilisearch.com/docs/reference/api/settings#filterable-attributes) of the [Index].
+    ///
+    /// The current filterable attributes are fetched first and written back with the new one appended, unless it was already there.
+    ///
+    /// **Note: the fetch and the update aren't atomic, see the [read-modify-write helpers](crate::settings#read-modify-write-helpers).**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("add_filterable_attribute", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("add_filterable_attribute");
+    ///
+    /// let task = index.add_filterable_attribute("genre").await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn add_filterable_attribute(
+        &self,
+        attribute: impl Into<String>,
+    ) -> Result<TaskInfo, Error> {
+        let attribute = FilterableAttribute::Attribute(attribute.into());
+        let mut filterable_attributes = self.get_filterable_attributes_advanced().await?;
+        if !filterable_attributes.contains(&attribute) {
+            filterable_attributes.push(attribute);
+        }
+
+        self.set_filterable_attributes_advanced(filterable_attributes)
+            .await
+    }
+
+    /// Remove an attribute from the [filterable attributes](https://www.meilisearch.com/docs/reference/api/settings#filterable-attributes) of the [Index].
+    ///
+    /// The current filterable attributes are fetched first and written back without the given one.
+    /// Only plain attribute names are removed, the attribute patterns are kept.
+    ///
+    /// **Note: the fetch and the update aren't atomic, see the [read-modify-write helpers](crate::settings#read-modify-write-helpers).**
+    pub async fn remove_filterable_attribute(
+        &self,
+        attribute: impl AsRef<str>,
+    ) -> Result<TaskInfo, Error> {
+        let attribute = attribute.as_ref();
+        let mut filterable_attributes = self.get_filterable_attributes_advanced().await?;
+        filterable_attributes.retain(|filterable| {
+            !matches!(filterable, FilterableAttribute::Attribute(name) if name == attribute)
+        });
+
+        self.set_filterable_attributes_advanced(filterable_attributes)
+            .await
+    }
+
     /// Update [sortable attributes](https://www.meilisearch.com/docs/reference/api/settings#sortable-attributes) of the [Index].
     ///
     /// # Example
@@ -1692,40 +2169,92 @@ impl<Http: HttpClient> Index<Http> {
             .await
     }
 
-    /// Update the [distinct attribute](https://www.meilisearch.com/docs/reference/api/settings#distinct-attribute) of the [Index].
+    /// Add an attribute to the [sortable attributes](https://www.meilisearch.com/docs/reference/api/settings#sortable-attributes) of the [Index].
+    ///
+    /// The current sortable attributes are fetched first and written back with the new one appended, unless it was already there.
+    ///
+    /// **Note: the fetch and the update aren't atomic, see the [read-modify-write helpers](crate::settings#read-modify-write-helpers).**
     ///
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// # use meilisearch_sdk::{client::*, indexes::*};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
     /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
-    /// # client.create_index("set_distinct_attribute", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("set_distinct_attribute");
+    /// # client.create_index("add_sortable_attribute", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("add_sortable_attribute");
     ///
-    /// let task = index.set_distinct_attribute("movie_id").await.unwrap();
+    /// let task = index.add_sortable_attribute("release_date").await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn set_distinct_attribute(
+    pub async fn add_sortable_attribute(
         &self,
-        distinct_attribute: impl AsRef<str>,
+        attribute: impl Into<String>,
     ) -> Result<TaskInfo, Error> {
-        self.client
-            .http_client
-            .request::<(), String, TaskInfo>(
-                &format!(
-                    "{}/indexes/{}/settings/distinct-attribute",
-                    self.client.host, self.uid
-                ),
-                Method::Put {
-                    query: (),
-                    body: distinct_attribute.as_ref().to_string(),
-                },
+        let attribute = attribute.into();
+        let mut sortable_attributes = self.get_sortable_attributes().await?;
+        if !sortable_attributes.contains(&attribute) {
+            sortable_attributes.push(attribute);
+        }
+
+        self.set_sortable_attributes(sortable_attributes).await
+    }
+
+    /// Remove an attribute from the [sortable attributes](https://www.meilisearch.com/docs/reference/api/settings#sortable-attributes) of the [Index].
+    ///
+    /// The current sortable attributes are fetched first and written back without the given one.
+    ///
+    /// **Note: the fetch and the update aren't atomic, see the [read-modify-write helpers](crate::settings#read-modify-write-helpers).**
+    pub async fn remove_sortable_attribute(
+        &self,
+        attribute: impl AsRef<str>,
+    ) -> Result<TaskInfo, Error> {
+        let attribute = attribute.as_ref();
+        let mut sortable_attributes = self.get_sortable_attributes().await?;
+        sortable_attributes.retain(|sortable| sortable != attribute);
+
+        self.set_sortable_attributes(sortable_attributes).await
+    }
+
+    /// Update the [distinct attribute](https://www.meilisearch.com/docs/reference/api/settings#distinct-attribute) of the [Index].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("set_distinct_attribute", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_distinct_attribute");
+    ///
+    /// let task = index.set_distinct_attribute("movie_id").await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_distinct_attribute(
+        &self,
+        distinct_attribute: impl AsRef<str>,
+    ) -> Result<TaskInfo, Error> {
+        self.client
+            .http_client
+            .request::<(), String, TaskInfo>(
+                &format!(
+                    "{}/indexes/{}/settings/distinct-attribute",
+                    self.client.host, self.uid
+                ),
+                Method::Put {
+                    query: (),
+                    body: distinct_attribute.as_ref().to_string(),
+                },
                 202,
             )
             .await
@@ -1773,6 +2302,62 @@ impl<Http: HttpClient> Index<Http> {
             .await
     }
 
+    /// Insert an attribute in the [searchable attributes](https://www.meilisearch.com/docs/reference/api/settings#searchable-attributes) of the [Index].
+    ///
+    /// Searchable attributes are ordered by importance, the attribute is inserted at `position` or appended if `position` is past the end of the list.
+    /// An attribute that was already searchable is moved to `position`.
+    /// The current searchable attributes are fetched first and written back with the new one.
+    ///
+    /// **Note: when every attribute is searchable Meilisearch returns `["*"]`, the attribute is then inserted next to the wildcard.**
+    ///
+    /// **Note: the fetch and the update aren't atomic, see the [read-modify-write helpers](crate::settings#read-modify-write-helpers).**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("insert_searchable_attribute_at", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("insert_searchable_attribute_at");
+    ///
+    /// let task = index.insert_searchable_attribute_at("title", 0).await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn insert_searchable_attribute_at(
+        &self,
+        attribute: &str,
+        position: usize,
+    ) -> Result<TaskInfo, Error> {
+        let mut searchable_attributes = self.get_searchable_attributes().await?;
+        searchable_attributes.retain(|searchable| searchable != attribute);
+        let position = position.min(searchable_attributes.len());
+        searchable_attributes.insert(position, attribute.to_string());
+
+        self.set_searchable_attributes(searchable_attributes).await
+    }
+
+    /// Remove an attribute from the [searchable attributes](https://www.meilisearch.com/docs/reference/api/settings#searchable-attributes) of the [Index].
+    ///
+    /// The current searchable attributes are fetched first and written back without the given one, keeping the order of the others.
+    ///
+    /// **Note: the fetch and the update aren't atomic, see the [read-modify-write helpers](crate::settings#read-modify-write-helpers).**
+    pub async fn remove_searchable_attribute(
+        &self,
+        attribute: impl AsRef<str>,
+    ) -> Result<TaskInfo, Error> {
+        let attribute = attribute.as_ref();
+        let mut searchable_attributes = self.get_searchable_attributes().await?;
+        searchable_attributes.retain(|searchable| searchable != attribute);
+
+        self.set_searchable_attributes(searchable_attributes).await
+    }
+
     /// Update [displayed attributes](https://www.meilisearch.com/docs/reference/api/settings#displayed-attributes) of the [Index].
     ///
     /// # Example
@@ -1815,6 +2400,45 @@ impl<Http: HttpClient> Index<Http> {
             .await
     }
 
+    /// Update [displayed attributes](https://www.meilisearch.com/docs/reference/api/settings#displayed-attributes) of the [Index] from [`DisplayedAttributes`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::DisplayedAttributes};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("update_displayed_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("update_displayed_attributes");
+    ///
+    /// let task = index.update_displayed_attributes(DisplayedAttributes::All).await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn update_displayed_attributes(
+        &self,
+        displayed_attributes: DisplayedAttributes,
+    ) -> Result<TaskInfo, Error> {
+        self.client
+            .http_client
+            .request::<(), &DisplayedAttributes, TaskInfo>(
+                &format!(
+                    "{}/indexes/{}/settings/displayed-attributes",
+                    self.client.host, self.uid
+                ),
+                Method::Put {
+                    query: (),
+                    body: &displayed_attributes,
+                },
+                202,
+            )
+            .await
+    }
+
     /// Update [faceting](https://www.meilisearch.com/docs/reference/api/settings#faceting) settings of the [Index].
     ///
     /// # Example
@@ -2924,6 +3548,580 @@ mod tests {
     use meilisearch_test_macro::meilisearch_test;
     use serde_json::{json, to_string};
 
+    const SETTINGS_UPDATE_TASK: &str = r#"{"taskUid":1,"indexUid":"movies","status":"enqueued","type":"settingsUpdate","enqueuedAt":"2024-10-11T11:49:53.000Z"}"#;
+
+    #[tokio::test]
+    async fn test_update_settings_with() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let index = client.index("movies");
+
+        let mock = s
+            .mock("PATCH", "/indexes/movies/settings")
+            .match_body(mockito::Matcher::Json(json!({
+                "rankingRules": ["words", "typo"],
+                "stopWords": []
+            })))
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(SETTINGS_UPDATE_TASK)
+            .create_async()
+            .await;
+
+        index
+            .update_settings_with(|settings| {
+                settings.ranking_rules = Some(vec!["words".to_string(), "typo".to_string()]);
+                settings.stop_words = Some(HashSet::new());
+            })
+            .await?;
+
+        mock.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_settings_and_wait() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let index = client.index("movies");
+
+        let update = s
+            .mock("PATCH", "/indexes/movies/settings")
+            .match_body(mockito::Matcher::Json(
+                json!({ "filterableAttributes": ["genre"] }),
+            ))
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(SETTINGS_UPDATE_TASK)
+            .expect(2)
+            .create_async()
+            .await;
+        let task = s
+            .mock("GET", "/tasks/1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"details":{"filterableAttributes":["genre"]},"duration":"PT0.1S","enqueuedAt":"2024-10-11T11:49:53.000Z","finishedAt":"2024-10-11T11:49:53.200Z","indexUid":"movies","startedAt":"2024-10-11T11:49:53.100Z","status":"succeeded","type":"settingsUpdate","uid":1}"#)
+            .create_async()
+            .await;
+
+        let settings = Settings::new().with_filterable_attributes(["genre"]);
+        index
+            .update_settings_and_wait(&settings, Duration::from_secs(1))
+            .await?;
+        task.remove_async().await;
+
+        let _failed_task = s
+            .mock("GET", "/tasks/1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"details":{"filterableAttributes":["genre"]},"duration":"PT0.1S","enqueuedAt":"2024-10-11T11:49:53.000Z","finishedAt":"2024-10-11T11:49:53.200Z","indexUid":"movies","startedAt":"2024-10-11T11:49:53.100Z","status":"failed","type":"settingsUpdate","uid":1,"error":{"message":"Index `movies` not found.","code":"index_not_found","type":"invalid_request","link":"https://docs.meilisearch.com/errors#index_not_found"}}"#)
+            .create_async()
+            .await;
+
+        assert!(matches!(
+            index
+                .update_settings_and_wait(&settings, Duration::from_secs(1))
+                .await,
+            Err(Error::Meilisearch(_))
+        ));
+        update.assert_async().await;
+        Ok(())
+    }
+
+    #[test]
+    fn test_default_settings_serialize_to_empty_object() {
+        assert_eq!(
+            serde_json::to_value(Settings::default()).unwrap(),
+            json!({})
+        );
+
+        let settings = Settings {
+            ranking_rules: Some(vec!["words".to_string()]),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(settings).unwrap(),
+            json!({ "rankingRules": ["words"] })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_add_and_remove_filterable_attribute() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let index = client.index("movies");
+        let path = "/indexes/movies/settings/filterable-attributes";
+        let rule = json!({
+            "attributePatterns": ["release_*"],
+            "features": {
+                "facetSearch": false,
+                "filter": { "equality": true, "comparison": true }
+            }
+        });
+
+        let _get = s
+            .mock("GET", path)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!(["genre", rule]).to_string())
+            .create_async()
+            .await;
+        let add = s
+            .mock("PUT", path)
+            .match_body(mockito::Matcher::Json(json!(["genre", rule, "year"])))
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(SETTINGS_UPDATE_TASK)
+            .create_async()
+            .await;
+        let add_existing = s
+            .mock("PUT", path)
+            .match_body(mockito::Matcher::Json(json!(["genre", rule])))
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(SETTINGS_UPDATE_TASK)
+            .create_async()
+            .await;
+        let remove = s
+            .mock("PUT", path)
+            .match_body(mockito::Matcher::Json(json!([rule])))
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(SETTINGS_UPDATE_TASK)
+            .create_async()
+            .await;
+
+        index.add_filterable_attribute("year").await?;
+        index.add_filterable_attribute("genre").await?;
+        index.remove_filterable_attribute("genre").await?;
+
+        add.assert_async().await;
+        add_existing.assert_async().await;
+        remove.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_add_and_remove_sortable_attribute() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let index = client.index("movies");
+        let path = "/indexes/movies/settings/sortable-attributes";
+
+        let _get = s
+            .mock("GET", path)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"["title","year"]"#)
+            .create_async()
+            .await;
+        let add = s
+            .mock("PUT", path)
+            .match_body(mockito::Matcher::Json(json!(["title", "year", "rating"])))
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(SETTINGS_UPDATE_TASK)
+            .create_async()
+            .await;
+        let add_existing = s
+            .mock("PUT", path)
+            .match_body(mockito::Matcher::Json(json!(["title", "year"])))
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(SETTINGS_UPDATE_TASK)
+            .create_async()
+            .await;
+        let remove = s
+            .mock("PUT", path)
+            .match_body(mockito::Matcher::Json(json!(["year"])))
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(SETTINGS_UPDATE_TASK)
+            .create_async()
+            .await;
+
+        index.add_sortable_attribute("rating").await?;
+        index.add_sortable_attribute("year").await?;
+        index.remove_sortable_attribute("title").await?;
+
+        add.assert_async().await;
+        add_existing.assert_async().await;
+        remove.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_insert_and_remove_searchable_attribute() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let index = client.index("movies");
+        let path = "/indexes/movies/settings/searchable-attributes";
+
+        let _get = s
+            .mock("GET", path)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"["title","overview"]"#)
+            .create_async()
+            .await;
+
+        for (attribute, position, expected) in [
+            ("genre", 0, json!(["genre", "title", "overview"])),
+            ("genre", 1, json!(["title", "genre", "overview"])),
+            ("genre", 10, json!(["title", "overview", "genre"])),
+            // an attribute already searchable is moved
+            ("overview", 0, json!(["overview", "title"])),
+            ("title", 10, json!(["overview", "title"])),
+            ("title", 0, json!(["title", "overview"])),
+        ] {
+            let put = s
+                .mock("PUT", path)
+                .match_body(mockito::Matcher::Json(expected))
+                .with_status(202)
+                .with_header("content-type", "application/json")
+                .with_body(SETTINGS_UPDATE_TASK)
+                .create_async()
+                .await;
+
+            index
+                .insert_searchable_attribute_at(attribute, position)
+                .await?;
+
+            put.assert_async().await;
+            put.remove_async().await;
+        }
+
+        let remove = s
+            .mock("PUT", path)
+            .match_body(mockito::Matcher::Json(json!(["overview"])))
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(SETTINGS_UPDATE_TASK)
+            .create_async()
+            .await;
+
+        index.remove_searchable_attribute("title").await?;
+
+        remove.assert_async().await;
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_settings() {
+        let some = || {
+            Settings::new()
+                .with_search_cutoff(100)
+                .with_distinct_attribute(Some("sku"))
+                .with_dictionary(["J. R. R."])
+        };
+        let other_some = || {
+            Settings::new()
+                .with_search_cutoff(200)
+                .with_distinct_attribute(None::<String>)
+                .with_dictionary(["W. E. B."])
+        };
+
+        // none + none
+        let merged = Settings::new().merge(Settings::new());
+        assert_eq!(merged.search_cutoff_ms, None);
+        assert_eq!(merged.distinct_attribute, None);
+        assert_eq!(merged.dictionary, None);
+
+        // some + none
+        let merged = some().merge(Settings::new());
+        assert_eq!(merged.search_cutoff_ms, Some(100));
+        assert_eq!(merged.distinct_attribute, Some(Some("sku".to_string())));
+        assert_eq!(merged.dictionary, Some(vec!["J. R. R.".to_string()]));
+
+        // none + some
+        let merged = Settings::new().merge(other_some());
+        assert_eq!(merged.search_cutoff_ms, Some(200));
+        assert_eq!(merged.distinct_attribute, Some(None));
+        assert_eq!(merged.dictionary, Some(vec!["W. E. B.".to_string()]));
+
+        // some + some, resetting the distinct attribute counts as a value
+        let merged = some().merge(other_some());
+        assert_eq!(merged.search_cutoff_ms, Some(200));
+        assert_eq!(merged.distinct_attribute, Some(None));
+        assert_eq!(merged.dictionary, Some(vec!["W. E. B.".to_string()]));
+    }
+
+    #[test]
+    fn test_deserialize_settings_view() {
+        let view: SettingsView = serde_json::from_value(json!({
+            "displayedAttributes": ["*"],
+            "searchableAttributes": ["*"],
+            "filterableAttributes": ["genre"],
+            "sortableAttributes": [],
+            "rankingRules": ["words", "typo", "proximity", "attribute", "sort", "exactness"],
+            "stopWords": ["the"],
+            "nonSeparatorTokens": [],
+            "separatorTokens": [],
+            "dictionary": [],
+            "synonyms": {},
+            "distinctAttribute": null,
+            "proximityPrecision": "byWord",
+            "typoTolerance": {
+                "enabled": true,
+                "minWordSizeForTypos": { "oneTypo": 5, "twoTypos": 9 },
+                "disableOnWords": [],
+                "disableOnAttributes": []
+            },
+            "faceting": { "maxValuesPerFacet": 100, "sortFacetValuesBy": { "*": "alpha" } },
+            "pagination": { "maxTotalHits": 1000 },
+            "searchCutoffMs": null,
+            "localizedAttributes": null
+        }))
+        .unwrap();
+
+        assert_eq!(view.displayed_attributes, DisplayedAttributes::All);
+        assert_eq!(view.ranking_rules.len(), 6);
+        assert_eq!(view.distinct_attribute, None);
+        assert_eq!(view.pagination.max_total_hits, 1000);
+        assert!(view.embedders.is_empty());
+
+        let settings = Settings::from(view);
+        assert_eq!(
+            settings.stop_words,
+            Some(HashSet::from(["the".to_string()]))
+        );
+        assert_eq!(settings.distinct_attribute, Some(None));
+        assert_eq!(settings.search_cutoff_ms, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_displayed_attributes_variants() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), Some("masterKey")).unwrap();
+        let path = "/indexes/movies/settings/displayed-attributes";
+
+        let _all = s
+            .mock("GET", path)
+            .with_status(200)
+            .with_body(r#"["*"]"#)
+            .create_async()
+            .await;
+        let _fields = s
+            .mock("GET", "/indexes/books/settings/displayed-attributes")
+            .with_status(200)
+            .with_body(r#"["title","author"]"#)
+            .create_async()
+            .await;
+
+        assert_eq!(
+            client.index("movies").get_displayed_attributes().await?,
+            DisplayedAttributes::All
+        );
+        assert_eq!(
+            client.index("books").get_displayed_attributes().await?,
+            DisplayedAttributes::Fields(vec!["title".to_string(), "author".to_string()])
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_displayed_attributes_variants() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), Some("masterKey")).unwrap();
+        let index = client.index("movies");
+        let path = "/indexes/movies/settings/displayed-attributes";
+
+        let all = s
+            .mock("PUT", path)
+            .match_body(mockito::Matcher::Json(json!(["*"])))
+            .with_status(202)
+            .with_body(SETTINGS_UPDATE_TASK)
+            .create_async()
+            .await;
+        index
+            .update_displayed_attributes(DisplayedAttributes::All)
+            .await?;
+        all.assert_async().await;
+
+        let fields = s
+            .mock("PUT", path)
+            .match_body(mockito::Matcher::Json(json!(["title"])))
+            .with_status(202)
+            .with_body(SETTINGS_UPDATE_TASK)
+            .create_async()
+            .await;
+        index
+            .update_displayed_attributes(DisplayedAttributes::Fields(vec!["title".to_string()]))
+            .await?;
+        fields.assert_async().await;
+        Ok(())
+    }
+
+    #[test]
+    fn test_settings_stop_words_drop_duplicates() {
+        let settings: Settings =
+            serde_json::from_value(json!({ "stopWords": ["the", "of", "the"] })).unwrap();
+        assert_eq!(
+            settings.stop_words,
+            Some(HashSet::from(["the".to_string(), "of".to_string()]))
+        );
+
+        let settings = Settings::new().with_stop_words(["a", "a", "the"]);
+        assert_eq!(settings.stop_words.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_add_and_remove_stop_word() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), Some("masterKey")).unwrap();
+        let index = client.index("movies");
+        let path = "/indexes/movies/settings/stop-words";
+
+        let _get = s
+            .mock("GET", path)
+            .with_status(200)
+            .with_body(r#"["the","of"]"#)
+            .create_async()
+            .await;
+
+        let put = s
+            .mock("PUT", path)
+            .match_body(mockito::Matcher::Json(json!(["the", "of", "to"])))
+            .with_status(202)
+            .with_body(SETTINGS_UPDATE_TASK)
+            .create_async()
+            .await;
+        index.add_stop_word("to").await?;
+        put.assert_async().await;
+
+        let put = s
+            .mock("PUT", path)
+            .match_body(mockito::Matcher::Json(json!(["the", "of"])))
+            .with_status(202)
+            .with_body(SETTINGS_UPDATE_TASK)
+            .create_async()
+            .await;
+        index.add_stop_word("the").await?;
+        put.assert_async().await;
+
+        let put = s
+            .mock("PUT", path)
+            .match_body(mockito::Matcher::Json(json!(["of"])))
+            .with_status(202)
+            .with_body(SETTINGS_UPDATE_TASK)
+            .create_async()
+            .await;
+        index.remove_stop_word("the").await?;
+        put.assert_async().await;
+        Ok(())
+    }
+
+    #[test]
+    fn test_synonyms_builder() {
+        let synonyms = SynonymsBuilder::new()
+            .add_synonym("wolverine", &["logan", "xmen"])
+            .add_synonym("logan", &["wolverine"])
+            .add_one_way("wow", &["world of warcraft", "wow"])
+            .build();
+
+        assert_eq!(synonyms.len(), 4);
+        assert_eq!(synonyms["wolverine"], ["logan", "xmen"]);
+        assert_eq!(synonyms["logan"], ["wolverine", "xmen"]);
+        assert_eq!(synonyms["xmen"], ["wolverine", "logan"]);
+        assert_eq!(synonyms["wow"], ["world of warcraft"]);
+    }
+
+    #[tokio::test]
+    async fn test_add_synonym_is_idempotent() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), Some("masterKey")).unwrap();
+        let index = client.index("movies");
+        let path = "/indexes/movies/settings/synonyms";
+
+        let existing = json!({
+            "wolverine": ["logan"],
+            "logan": ["wolverine"],
+            "wow": ["world of warcraft"]
+        });
+        let _get = s
+            .mock("GET", path)
+            .with_status(200)
+            .with_body(existing.to_string())
+            .expect(2)
+            .create_async()
+            .await;
+        let put = s
+            .mock("PUT", path)
+            .match_body(mockito::Matcher::Json(json!({
+                "wolverine": ["logan"],
+                "logan": ["wolverine"],
+                "wow": ["world of warcraft"]
+            })))
+            .with_status(202)
+            .with_body(SETTINGS_UPDATE_TASK)
+            .create_async()
+            .await;
+
+        index.add_synonym("wolverine", &["logan"]).await?;
+        put.assert_async().await;
+
+        let put = s
+            .mock("PUT", path)
+            .match_body(mockito::Matcher::Json(json!({
+                "wolverine": ["logan", "xmen"],
+                "logan": ["wolverine"],
+                "xmen": ["wolverine"],
+                "wow": ["world of warcraft"]
+            })))
+            .with_status(202)
+            .with_body(SETTINGS_UPDATE_TASK)
+            .create_async()
+            .await;
+
+        index.add_synonym("wolverine", &["xmen"]).await?;
+        put.assert_async().await;
+        Ok(())
+    }
+
+    #[test]
+    fn test_filterable_attribute_round_trip() {
+        let simple = FilterableAttribute::from(String::from("author"));
+        let value = serde_json::to_value(&simple).unwrap();
+        assert_eq!(value, json!("author"));
+        assert_eq!(
+            serde_json::from_value::<FilterableAttribute>(value).unwrap(),
+            simple
+        );
+
+        let rule = FilterableAttribute::Settings(FilterableAttributesSettings {
+            attribute_patterns: vec!["genre*".to_string()],
+            features: FilterFeatures {
+                facet_search: false,
+                filter: FilterFeatureModes {
+                    equality: true,
+                    comparison: true,
+                },
+            },
+        });
+        let value = serde_json::to_value(&rule).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "attributePatterns": ["genre*"],
+                "features": {
+                    "facetSearch": false,
+                    "filter": { "equality": true, "comparison": true }
+                }
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<FilterableAttribute>(value).unwrap(),
+            rule
+        );
+
+        // both forms can be mixed in the settings
+        let settings: Settings = serde_json::from_value(json!({
+            "filterableAttributes": ["author", serde_json::to_value(&rule).unwrap()]
+        }))
+        .unwrap();
+        assert_eq!(settings.filterable_attributes, Some(vec![simple, rule]));
+    }
+
     #[test]
     fn test_settings_with_filterable_attributes_advanced_builder() {
         let attrs = vec![
@@ -32,7 +32,7 @@ pub struct SimilarResults<T> {
     /// Performance trace of the query
     pub performance_details: Option<Value>,
     /// Processing time of the query
-    pub processing_time_ms: usize,
+    pub processing_time_ms: u64,
     /// Identifier of the target document
     pub id: String,
 }
@@ -13,19 +13,31 @@ pub struct TaskInfo {
     pub status: String,
     #[serde(flatten)]
     pub update_type: TaskType,
-    pub task_uid: u32,
+    pub task_uid: TaskUid,
 }
 
 impl AsRef<u32> for TaskInfo {
     fn as_ref(&self) -> &u32 {
-        &self.task_uid
+        &self.task_uid.0
+    }
+}
+
+impl From<TaskInfo> for TaskUid {
+    fn from(task_info: TaskInfo) -> Self {
+        task_info.task_uid
+    }
+}
+
+impl From<&TaskInfo> for TaskUid {
+    fn from(task_info: &TaskInfo) -> Self {
+        task_info.task_uid
     }
 }
 
 impl TaskInfo {
     #[must_use]
     pub fn get_task_uid(&self) -> u32 {
-        self.task_uid
+        self.task_uid.0
     }
 
     /// Wait until Meilisearch processes a task provided by [`TaskInfo`], and get its status.
@@ -80,6 +92,22 @@ impl TaskInfo {
     ) -> Result<Task, Error> {
         client.wait_for_task(self, interval, timeout).await
     }
+
+    /// Wait like [`TaskInfo::wait_for_completion`] and return the error of the task if it failed.
+    pub(crate) async fn wait_for_success<Http: HttpClient>(
+        self,
+        client: &Client<Http>,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        let task = self
+            .wait_for_completion(client, None, Some(timeout))
+            .await?;
+        if task.is_failure() {
+            return Err(task.unwrap_failure().into());
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -127,13 +155,53 @@ mod test {
             TaskInfo {
                 enqueued_at,
                 index_uid: Some(index_uid),
-                task_uid: 12,
+                task_uid: TaskUid(12),
                 update_type: TaskType::DocumentAdditionOrUpdate { details: None },
                 status,
             }
         if enqueued_at == datetime && index_uid == "meili" && status == "enqueued"));
     }
 
+    #[test]
+    fn test_task_info_uid_is_a_task_uid() {
+        let task_info: TaskInfo = serde_json::from_str(
+            r#"{"enqueuedAt":"2022-02-03T13:02:38.369634Z","indexUid":"meili","status":"enqueued","type":"documentAdditionOrUpdate","taskUid":12}"#,
+        )
+        .unwrap();
+
+        let uid: TaskUid = task_info.task_uid;
+        assert_eq!(uid, TaskUid(12));
+        assert_eq!(uid, 12);
+        assert_eq!(task_info.get_task_uid(), 12);
+    }
+
+    #[tokio::test]
+    async fn test_task_info_enqueued_at_from_response() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        let _m = s
+            .mock("DELETE", "/indexes/movies")
+            .with_status(202)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"taskUid":3,"indexUid":"movies","status":"enqueued","type":"indexDeletion","enqueuedAt":"2024-10-11T11:49:53.123Z"}"#)
+            .create_async()
+            .await;
+
+        let task_info = client.delete_index("movies").await?;
+
+        assert_eq!(
+            task_info.enqueued_at,
+            OffsetDateTime::parse(
+                "2024-10-11T11:49:53.123Z",
+                &time::format_description::well_known::Rfc3339,
+            )
+            .unwrap()
+        );
+        assert_eq!(task_info.enqueued_at.millisecond(), 123);
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_wait_for_task_with_args(client: Client, movies: Index) -> Result<(), Error> {
         let task_info = movies
@@ -52,6 +52,9 @@ pub enum TaskType {
     IndexCompaction {
         details: Option<IndexCompaction>,
     },
+    UpgradeDatabase {
+        details: Option<UpgradeDatabase>,
+    },
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -63,6 +66,24 @@ pub struct TasksResults {
     pub next: Option<u32>,
 }
 
+impl IntoIterator for TasksResults {
+    type Item = Task;
+    type IntoIter = std::vec::IntoIter<Task>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a TasksResults {
+    type Item = &'a Task;
+    type IntoIter = std::slice::Iter<'a, Task>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.iter()
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DocumentAdditionOrUpdate {
@@ -104,6 +125,15 @@ pub struct SnapshotCreation {}
 #[serde(rename_all = "camelCase")]
 pub struct IndexCompaction {}
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpgradeDatabase {
+    #[serde(rename = "upgradeFrom")]
+    pub from_version: String,
+    #[serde(rename = "upgradeTo")]
+    pub to_version: String,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NetworkTopologyChangeDetails {
@@ -500,6 +530,80 @@ impl AsRef<u32> for Task {
     }
 }
 
+/// The uid of a [`Task`].
+///
+/// It can be built from a raw `u32`, a [`Task`] or a [`TaskInfo`], and serializes as a plain integer.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::tasks::TaskUid;
+/// let uid = TaskUid::from(42);
+///
+/// assert_eq!(u32::from(uid), 42);
+/// assert_eq!(uid.to_string(), "42");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TaskUid(pub u32);
+
+impl std::fmt::Display for TaskUid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl AsRef<u32> for TaskUid {
+    fn as_ref(&self) -> &u32 {
+        &self.0
+    }
+}
+
+impl PartialEq<u32> for TaskUid {
+    fn eq(&self, other: &u32) -> bool {
+        self.0 == *other
+    }
+}
+
+impl From<u32> for TaskUid {
+    fn from(uid: u32) -> Self {
+        TaskUid(uid)
+    }
+}
+
+impl From<&u32> for TaskUid {
+    fn from(uid: &u32) -> Self {
+        TaskUid(*uid)
+    }
+}
+
+impl From<&TaskUid> for TaskUid {
+    fn from(uid: &TaskUid) -> Self {
+        *uid
+    }
+}
+
+impl From<TaskUid> for u32 {
+    fn from(uid: TaskUid) -> Self {
+        uid.0
+    }
+}
+
+impl From<Task> for TaskUid {
+    fn from(task: Task) -> Self {
+        TaskUid(task.get_uid())
+    }
+}
+
+impl From<&Task> for TaskUid {
+    fn from(task: &Task) -> Self {
+        TaskUid(task.get_uid())
+    }
+}
+
+/// Maximum `limit` accepted by the `/tasks` route.
+const MAX_TASKS_LIMIT: u32 = 20_000;
+
 #[derive(Debug, Serialize, Clone)]
 pub struct TasksPaginationFilters {
     /// Maximum number of tasks to return.
@@ -745,11 +849,14 @@ impl<'a, Http: HttpClient> TasksQuery<'a, TasksPaginationFilters, Http> {
             reverse: None,
         }
     }
+    /// Maximum number of tasks to return.
+    ///
+    /// Meilisearch doesn't accept more than 20 000 tasks per page, larger values are capped.
     pub fn with_limit<'b>(
         &'b mut self,
         limit: u32,
     ) -> &'b mut TasksQuery<'a, TasksPaginationFilters, Http> {
-        self.pagination.limit = Some(limit);
+        self.pagination.limit = Some(limit.min(MAX_TASKS_LIMIT));
         self
     }
     pub fn with_from<'b>(
@@ -831,6 +938,83 @@ mod test {
         kind: String,
     }
 
+    #[test]
+    fn test_iterate_over_tasks_results() {
+        let tasks: TasksResults = serde_json::from_str(
+            r#"{
+                "results": [
+                    { "uid": 1, "indexUid": null, "status": "enqueued", "type": "dumpCreation", "enqueuedAt": "2025-02-17T10:00:00Z" },
+                    { "uid": 0, "indexUid": null, "status": "enqueued", "type": "dumpCreation", "enqueuedAt": "2025-02-17T10:00:00Z" }
+                ],
+                "total": 2,
+                "limit": 20,
+                "from": 1,
+                "next": null
+            }"#,
+        )
+        .unwrap();
+
+        let mut uids = Vec::new();
+        for task in &tasks {
+            uids.push(task.get_uid());
+        }
+        for task in tasks {
+            uids.push(task.get_uid());
+        }
+        assert_eq!(uids, vec![1, 0, 1, 0]);
+    }
+
+    #[test]
+    fn test_deserialize_upgrade_database_task() {
+        let task: Task = serde_json::from_str(
+            r#"
+{
+  "uid": 0,
+  "batchUid": 0,
+  "indexUid": null,
+  "status": "succeeded",
+  "type": "upgradeDatabase",
+  "canceledBy": null,
+  "details": {
+    "upgradeFrom": "v1.12.0",
+    "upgradeTo": "v1.13.0"
+  },
+  "error": null,
+  "duration": "PT0.0048S",
+  "enqueuedAt": "2025-02-17T10:00:00.000000Z",
+  "startedAt": "2025-02-17T10:00:00.100000Z",
+  "finishedAt": "2025-02-17T10:00:00.104800Z"
+}"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            task,
+            Task::Succeeded {
+                content: SucceededTask {
+                    update_type: TaskType::UpgradeDatabase {
+                        details: Some(UpgradeDatabase {
+                            ref from_version,
+                            ref to_version,
+                        })
+                    },
+                    ..
+                }
+            }
+            if from_version == "v1.12.0" && to_version == "v1.13.0"
+        ));
+    }
+
+    #[test]
+    fn test_task_uid_serializes_as_integer() {
+        let uid = TaskUid::from(42);
+
+        assert_eq!(serde_json::to_string(&uid).unwrap(), "42");
+        assert_eq!(serde_json::from_str::<TaskUid>("42").unwrap(), uid);
+        assert_eq!(uid.to_string(), "42");
+        assert_eq!(u32::from(uid), 42);
+    }
+
     #[test]
     fn test_deserialize_task() {
         let datetime = OffsetDateTime::parse(
@@ -1011,6 +1195,28 @@ mod test {
         Ok(())
     }
 
+    #[meilisearch_test]
+    async fn test_get_tasks_limit_is_capped() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let mock_server_url = s.url();
+        let client = Client::new(mock_server_url, Some("masterKey")).unwrap();
+
+        let mock_res = s
+            .mock("GET", "/tasks?limit=20000")
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let mut query = TasksSearchQuery::new(&client);
+        query.with_limit(20_001);
+
+        let _ = client.get_tasks_with(&query).await;
+
+        mock_res.assert_async().await;
+
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_get_tasks_with_date_params() -> Result<(), Error> {
         let mut s = mockito::Server::new_async().await;
@@ -1,7 +1,7 @@
 use crate::errors::Error;
 use jsonwebtoken::{encode, EncodingKey, Header};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{json, Value};
 use time::OffsetDateTime;
 #[cfg(not(target_arch = "wasm32"))]
 use uuid::Uuid;
@@ -15,7 +15,45 @@ struct TenantTokenClaim {
     exp: Option<OffsetDateTime>,
 }
 
-pub fn generate_tenant_token(
+/// The search rules embedded in a tenant token.
+///
+/// Can be passed to [`Client::generate_tenant_token`](crate::client::Client::generate_tenant_token)
+/// in place of a raw [`Value`].
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::tenant_tokens::TenantTokenSearchRules;
+/// # use serde_json::{json, Value};
+/// let rules: Value = TenantTokenSearchRules::allow_all_with_filter("user_id = 1").into();
+///
+/// assert_eq!(rules, json!({ "*": { "filter": "user_id = 1" } }));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TenantTokenSearchRules(Value);
+
+impl TenantTokenSearchRules {
+    /// Grants access to every index, generating `{ "*": {} }`.
+    #[must_use]
+    pub fn allow_all() -> Self {
+        TenantTokenSearchRules(json!({ "*": {} }))
+    }
+
+    /// Grants access to every index with the given filter applied to all searches,
+    /// generating `{ "*": { "filter": "..." } }`.
+    #[must_use]
+    pub fn allow_all_with_filter(filter: &str) -> Self {
+        TenantTokenSearchRules(json!({ "*": { "filter": filter } }))
+    }
+}
+
+impl From<TenantTokenSearchRules> for Value {
+    fn from(rules: TenantTokenSearchRules) -> Value {
+        rules.0
+    }
+}
+
+pub(crate) fn generate_tenant_token(
     api_key_uid: String,
     search_rules: Value,
     api_key: impl AsRef<str>,
@@ -53,7 +91,6 @@ mod tests {
     use crate::tenant_tokens::*;
     use big_s::S;
     use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
-    use serde_json::json;
     use std::collections::HashSet;
 
     const SEARCH_RULES: [&str; 1] = ["*"];
@@ -174,4 +211,49 @@ mod tests {
 
         assert!(token.is_err());
     }
+
+    #[test]
+    fn test_generate_token_allow_all() {
+        let api_key_uid = S("76cf8b87-fd12-4688-ad34-260d930ca4f4");
+        let token = generate_tenant_token(
+            api_key_uid,
+            TenantTokenSearchRules::allow_all().into(),
+            VALID_KEY,
+            None,
+        )
+        .unwrap();
+
+        let decoded = decode::<TenantTokenClaim>(
+            &token,
+            &DecodingKey::from_secret(VALID_KEY.as_ref()),
+            &build_validation(),
+        )
+        .expect("Cannot decode the token");
+
+        assert_eq!(decoded.claims.search_rules, json!({ "*": {} }));
+    }
+
+    #[test]
+    fn test_generate_token_allow_all_with_filter() {
+        let api_key_uid = S("76cf8b87-fd12-4688-ad34-260d930ca4f4");
+        let token = generate_tenant_token(
+            api_key_uid,
+            TenantTokenSearchRules::allow_all_with_filter("user_id = 1").into(),
+            VALID_KEY,
+            None,
+        )
+        .unwrap();
+
+        let decoded = decode::<TenantTokenClaim>(
+            &token,
+            &DecodingKey::from_secret(VALID_KEY.as_ref()),
+            &build_validation(),
+        )
+        .expect("Cannot decode the token");
+
+        assert_eq!(
+            decoded.claims.search_rules,
+            json!({ "*": { "filter": "user_id = 1" } })
+        );
+    }
 }
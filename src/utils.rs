@@ -1,4 +1,10 @@
-use std::time::Duration;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures_io::AsyncRead;
 
 #[derive(Debug, Copy, Clone)]
 pub(crate) enum SleepBackend {
@@ -62,6 +68,45 @@ impl SleepBackend {
     }
 }
 
+/// Wraps a request body to report how many bytes have been read from it so far.
+pub(crate) struct ProgressBody<R, F> {
+    inner: R,
+    sent: usize,
+    total: usize,
+    on_progress: F,
+}
+
+impl<R, F: Fn(usize, usize)> ProgressBody<R, F> {
+    pub(crate) fn new(inner: R, total: usize, on_progress: F) -> Self {
+        ProgressBody {
+            inner,
+            sent: 0,
+            total,
+            on_progress,
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin, F: Fn(usize, usize) + Unpin> AsyncRead for ProgressBody<R, F> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+        let poll = Pin::new(&mut this.inner).poll_read(cx, buf);
+
+        if let Poll::Ready(Ok(read)) = poll {
+            if read > 0 {
+                this.sent += read;
+                (this.on_progress)(this.sent, this.total);
+            }
+        }
+
+        poll
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;